@@ -1,10 +1,16 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-use solar_bench::{get_srcs, Source, PARSERS};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use solar_bench::{count_tokens, get_srcs, Source, PARSERS};
 use std::time::Duration;
 
 fn parser_benches(c: &mut Criterion) {
     for s in get_srcs() {
-        eprintln!("{}: {} LoC, {} bytes", s.name, s.src.lines().count(), s.src.len());
+        let tokens = count_tokens(s.src);
+        eprintln!(
+            "{}: {} LoC, {} bytes, {tokens} tokens",
+            s.name,
+            s.src.lines().count(),
+            s.src.len()
+        );
     }
     eprintln!();
 
@@ -16,6 +22,10 @@ fn parser_benches(c: &mut Criterion) {
 
     solar_parse::interface::enter(|| {
         for &Source { name: sname, path: _, src } in get_srcs() {
+            // Report lexing throughput in tokens per second and parsing throughput in lines per
+            // second.
+            let tokens = count_tokens(src) as u64;
+            let lines = src.lines().count() as u64;
             for &parser in PARSERS {
                 let pname = parser.name();
                 let mk_id = |id: &str| {
@@ -26,8 +36,10 @@ fn parser_benches(c: &mut Criterion) {
                     }
                 };
                 if parser.can_lex() {
+                    g.throughput(Throughput::Elements(tokens));
                     g.bench_function(mk_id("lex"), |b| b.iter(|| parser.lex(src)));
                 }
+                g.throughput(Throughput::Elements(lines));
                 g.bench_function(mk_id("parse"), |b| b.iter(|| parser.parse(src)));
             }
             eprintln!();
@@ -120,13 +120,23 @@ impl Parser for Solar {
     }
 }
 
-fn session() -> Session {
+/// Creates a single-threaded session for benchmarking.
+///
+/// Diagnostics are emitted to stderr without colors, so that the output is the same whether or not
+/// it is a terminal.
+pub fn session() -> Session {
     Session::builder()
-        .with_stderr_emitter_and_color(solar_parse::interface::ColorChoice::Always)
+        .with_stderr_emitter_and_color(solar_parse::interface::ColorChoice::Never)
         .single_threaded()
         .build()
 }
 
+/// Returns the number of tokens in `src`, excluding comments, as lexed by Solar.
+pub fn count_tokens(src: &str) -> usize {
+    let sess = session();
+    sess.enter(|| solar_parse::Lexer::new(&sess, src).keep_comments(false).into_tokens().len())
+}
+
 pub struct Solang;
 impl Parser for Solang {
     fn name(&self) -> &'static str {
@@ -1,8 +1,33 @@
 use super::{Box, Lit, SubDenomination, Type};
 use either::Either;
-use solar_interface::{Ident, Span};
+use solar_interface::{kw, sym, Ident, Span, Symbol};
 use std::fmt;
 
+/// Member names with a special meaning on addresses and function values, such as `addr.code` and
+/// `this.f.selector`.
+///
+/// These are parsed as regular [member accesses](ExprKind::Member).
+pub const MAGIC_MEMBERS: [Symbol; 5] =
+    [kw::Address, kw::Balance, sym::code, sym::codehash, sym::selector];
+
+/// Returns `true` if `name` is one of the [`MAGIC_MEMBERS`].
+#[inline]
+pub fn is_magic_member(name: Symbol) -> bool {
+    MAGIC_MEMBERS.contains(&name)
+}
+
+/// Member functions that mutate dynamic storage arrays and `bytes`: `arr.push()`, `arr.push(x)`
+/// and `arr.pop()`.
+///
+/// These are parsed as regular [member](ExprKind::Member) [calls](ExprKind::Call).
+pub const ARRAY_MUTATORS: [Symbol; 2] = [sym::push, kw::Pop];
+
+/// Returns `true` if `name` is one of the [`ARRAY_MUTATORS`].
+#[inline]
+pub fn is_array_mutator(name: Symbol) -> bool {
+    ARRAY_MUTATORS.contains(&name)
+}
+
 /// A list of named arguments: `{a: "1", b: 2}`.
 pub type NamedArgList<'ast> = Box<'ast, [NamedArg<'ast>]>;
 
@@ -142,6 +167,15 @@ pub enum BinOpKind {
     Rem,
 }
 
+/// The associativity of a binary operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Assoc {
+    /// `a op b op c` is parsed as `(a op b) op c`.
+    Left,
+    /// `a op b op c` is parsed as `a op (b op c)`.
+    Right,
+}
+
 impl fmt::Display for BinOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.kind.to_str())
@@ -149,6 +183,45 @@ impl fmt::Display for BinOp {
 }
 
 impl BinOpKind {
+    /// The binary operators grouped by precedence, from lowest to highest, with their
+    /// associativity.
+    ///
+    /// Reference: <https://docs.soliditylang.org/en/latest/cheatsheet.html#order-of-precedence-of-operators>
+    pub const PRECEDENCE: [(&'static [Self], Assoc); 11] = [
+        (&[Self::Or], Assoc::Left),
+        (&[Self::And], Assoc::Left),
+        (&[Self::Eq, Self::Ne], Assoc::Left),
+        (&[Self::Lt, Self::Gt, Self::Le, Self::Ge], Assoc::Left),
+        (&[Self::BitOr], Assoc::Left),
+        (&[Self::BitXor], Assoc::Left),
+        (&[Self::BitAnd], Assoc::Left),
+        (&[Self::Shl, Self::Shr, Self::Sar], Assoc::Left),
+        (&[Self::Add, Self::Sub], Assoc::Left),
+        (&[Self::Mul, Self::Div, Self::Rem], Assoc::Left),
+        // Since 0.8.0, `a**b**c` is parsed as `a**(b**c)`.
+        (&[Self::Pow], Assoc::Right),
+    ];
+
+    /// Returns the precedence and associativity of the operator, as defined by
+    /// [`PRECEDENCE`](Self::PRECEDENCE).
+    ///
+    /// Operators with a higher precedence bind tighter. The lowest precedence is `1`.
+    pub const fn precedence(self) -> (usize, Assoc) {
+        match self {
+            Self::Or => (1, Assoc::Left),
+            Self::And => (2, Assoc::Left),
+            Self::Eq | Self::Ne => (3, Assoc::Left),
+            Self::Lt | Self::Gt | Self::Le | Self::Ge => (4, Assoc::Left),
+            Self::BitOr => (5, Assoc::Left),
+            Self::BitXor => (6, Assoc::Left),
+            Self::BitAnd => (7, Assoc::Left),
+            Self::Shl | Self::Shr | Self::Sar => (8, Assoc::Left),
+            Self::Add | Self::Sub => (9, Assoc::Left),
+            Self::Mul | Self::Div | Self::Rem => (10, Assoc::Left),
+            Self::Pow => (11, Assoc::Right),
+        }
+    }
+
     /// Returns the string representation of the operator.
     pub const fn to_str(self) -> &'static str {
         match self {
@@ -337,3 +410,73 @@ pub enum IndexKind<'ast> {
     /// A slice: `slice[l:r]`.
     Range(Option<Box<'ast, Expr<'ast>>>, Option<Box<'ast, Expr<'ast>>>),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn magic_members() {
+        solar_interface::enter(|| {
+            for name in ["address", "balance", "code", "codehash", "selector"] {
+                assert!(is_magic_member(Symbol::intern(name)), "{name}");
+            }
+            for name in ["length", "push", "value", "gas", "Address"] {
+                assert!(!is_magic_member(Symbol::intern(name)), "{name}");
+            }
+        });
+    }
+
+    #[test]
+    fn binop_precedence() {
+        use BinOpKind::*;
+
+        // From the "Order of Precedence of Operators" table in the Solidity docs, from highest to
+        // lowest; unary, postfix, ternary and assignment operators are not binary operators.
+        let spec: &[&[BinOpKind]] = &[
+            &[Pow],
+            &[Mul, Div, Rem],
+            &[Add, Sub],
+            &[Shl, Shr, Sar],
+            &[BitAnd],
+            &[BitXor],
+            &[BitOr],
+            &[Lt, Gt, Le, Ge],
+            &[Eq, Ne],
+            &[And],
+            &[Or],
+        ];
+        for (i, ops) in spec.iter().rev().enumerate() {
+            for &op in *ops {
+                assert_eq!(op.precedence().0, i + 1, "{op:?}");
+            }
+        }
+        let all = spec.iter().flat_map(|ops| ops.iter()).count();
+        let table = BinOpKind::PRECEDENCE.iter().map(|(ops, _)| ops.len()).sum::<usize>();
+        assert_eq!(all, table);
+
+        // `precedence` must agree with the table.
+        for (i, &(ops, assoc)) in BinOpKind::PRECEDENCE.iter().enumerate() {
+            for &op in ops {
+                assert_eq!(op.precedence(), (i + 1, assoc), "{op:?}");
+            }
+        }
+
+        assert_eq!(Pow.precedence().1, Assoc::Right);
+        for op in [Or, And, Eq, Lt, BitOr, BitXor, BitAnd, Shl, Add, Sub, Mul, Div] {
+            assert_eq!(op.precedence().1, Assoc::Left, "{op:?}");
+        }
+    }
+
+    #[test]
+    fn array_mutators() {
+        solar_interface::enter(|| {
+            for name in ["push", "pop"] {
+                assert!(is_array_mutator(Symbol::intern(name)), "{name}");
+            }
+            for name in ["length", "concat", "Push", "delete"] {
+                assert!(!is_array_mutator(Symbol::intern(name)), "{name}");
+            }
+        });
+    }
+}
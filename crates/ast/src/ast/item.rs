@@ -188,6 +188,63 @@ impl PragmaTokens<'_> {
             _ => None,
         }
     }
+
+    /// Returns the kind of the pragma directive.
+    pub fn kind(&self) -> PragmaKind<'_> {
+        let Some((name, value)) = self.as_name_and_value() else {
+            return match self {
+                Self::Version(..) => PragmaKind::Version,
+                _ => PragmaKind::Unknown,
+            };
+        };
+        match (name.as_str(), value.map(IdentOrStrLit::as_str)) {
+            ("abicoder", Some("v1")) => PragmaKind::Abicoder(AbiCoderVersion::V1),
+            ("abicoder", Some("v2")) => PragmaKind::Abicoder(AbiCoderVersion::V2),
+            ("experimental", Some(feature)) => PragmaKind::Experimental(match feature {
+                "ABIEncoderV2" => ExperimentalFeature::AbiEncoderV2,
+                "SMTChecker" => ExperimentalFeature::SmtChecker,
+                "solidity" => ExperimentalFeature::Solidity,
+                feature => ExperimentalFeature::Unknown(feature),
+            }),
+            _ => PragmaKind::Unknown,
+        }
+    }
+}
+
+/// The kind of a pragma directive. See [`PragmaTokens::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PragmaKind<'a> {
+    /// A version pragma: `pragma solidity ^0.8.0;`.
+    Version,
+    /// `pragma abicoder <version>;`.
+    Abicoder(AbiCoderVersion),
+    /// `pragma experimental <feature>;`.
+    Experimental(ExperimentalFeature<'a>),
+    /// Any other pragma.
+    Unknown,
+}
+
+/// The ABI coder version selected by `pragma abicoder`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AbiCoderVersion {
+    /// `v1`.
+    V1,
+    /// `v2`.
+    V2,
+}
+
+/// A feature enabled by `pragma experimental`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExperimentalFeature<'a> {
+    /// `ABIEncoderV2`.
+    AbiEncoderV2,
+    /// `SMTChecker`.
+    SmtChecker,
+    /// `solidity`.
+    Solidity,
+    /// A feature that is not known to the compiler. The name is preserved so that newer
+    /// features can still be parsed.
+    Unknown(&'a str),
 }
 
 /// An identifier or a string literal.
@@ -342,6 +399,7 @@ pub struct ItemContract<'ast> {
     pub kind: ContractKind,
     pub name: Ident,
     pub bases: Box<'ast, [Modifier<'ast>]>,
+    /// The members of the contract, in source order.
     pub body: Box<'ast, [Item<'ast>]>,
 }
 
@@ -1,7 +1,9 @@
 //! Solidity AST.
 
-use solar_data_structures::{index::IndexSlice, newtype_index, BumpExt};
-use std::fmt;
+use crate::visit::Visit;
+use solar_data_structures::{index::IndexSlice, newtype_index, BumpExt, Never};
+use solar_interface::BytePos;
+use std::{fmt, ops::ControlFlow};
 
 pub use crate::token::CommentKind;
 pub use solar_interface::{Ident, Span, Symbol};
@@ -43,12 +45,14 @@ impl Arena {
         Self { bump: bumpalo::Bump::new(), literals: typed_arena::Arena::new() }
     }
 
+    /// Returns the total number of bytes allocated by the arena, including unused capacity.
     pub fn allocated_bytes(&self) -> usize {
         self.bump.allocated_bytes()
             + (self.literals.len() + self.literals.uninitialized_array().len())
                 * std::mem::size_of::<Lit>()
     }
 
+    /// Returns the number of bytes occupied by the nodes allocated in the arena.
     pub fn used_bytes(&self) -> usize {
         self.bump.used_bytes() + self.literals.len() * std::mem::size_of::<Lit>()
     }
@@ -107,6 +111,11 @@ impl DocComments<'_> {
     pub fn span(&self) -> Span {
         Span::join_first_last(self.iter().map(|d| d.span))
     }
+
+    /// Returns the first `@inheritdoc` tag in the doc-comments, if any.
+    pub fn inheritdoc(&self) -> Option<InheritDoc> {
+        self.iter().find_map(InheritDoc::from_comment)
+    }
 }
 
 /// A single doc-comment: `/// foo`, `/** bar */`.
@@ -121,10 +130,107 @@ pub struct DocComment {
     pub symbol: Symbol,
 }
 
+/// An SPDX license identifier comment: `// SPDX-License-Identifier: MIT`.
+///
+/// Reference: <https://docs.soliditylang.org/en/latest/layout-of-source-files.html#spdx-license-identifier>
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpdxLicense {
+    /// The span of the comment containing the license identifier.
+    pub span: Span,
+    /// The license expression, such as `MIT` or `GPL-2.0-or-later OR MIT`.
+    pub value: Symbol,
+}
+
+impl SpdxLicense {
+    /// The prefix of the license identifier inside of the comment.
+    pub const PREFIX: &'static str = "SPDX-License-Identifier:";
+
+    /// Extracts the license identifier from the given comment, if any.
+    ///
+    /// Like `solc`, the license expression consists of the ASCII alphanumeric characters, spaces,
+    /// and `()+.-` following the prefix, with surrounding whitespace trimmed.
+    pub fn from_comment(comment: &DocComment) -> Option<Self> {
+        let text = comment.symbol.as_str();
+        let start = text.find(Self::PREFIX)? + Self::PREFIX.len();
+        let text = &text[start..];
+        let end = text
+            .find(|c: char| !(c.is_ascii_alphanumeric() || " ()+.-".contains(c)))
+            .unwrap_or(text.len());
+        let value = text[..end].trim();
+        if value.is_empty() {
+            return None;
+        }
+        Some(Self { span: comment.span, value: Symbol::intern(value) })
+    }
+}
+
+/// A NatSpec `@inheritdoc` tag: `/// @inheritdoc IERC20`.
+///
+/// Reference: <https://docs.soliditylang.org/en/latest/natspec-format.html#tags>
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InheritDoc {
+    /// The span of the tag, from `@inheritdoc` to the end of the path.
+    pub span: Span,
+    /// The path to the contract whose documentation is inherited, such as `IERC20` or
+    /// `Lib.IERC20`.
+    pub path: Vec<Ident>,
+}
+
+impl InheritDoc {
+    /// The name of the tag, including the `@`.
+    pub const TAG: &'static str = "@inheritdoc";
+
+    /// Extracts the first `@inheritdoc` tag from the given comment, if any.
+    ///
+    /// Returns `None` if the tag is not followed by a path, or if the path has an empty segment,
+    /// such as `Foo.` or `Foo..Bar`.
+    pub fn from_comment(comment: &DocComment) -> Option<Self> {
+        let text = comment.symbol.as_str();
+        // The symbol excludes the opening `///` or `/**`.
+        let content_lo = comment.span.lo() + BytePos(3);
+        let span_of = |start: usize, end: usize| {
+            Span::new(
+                content_lo + BytePos::from_usize(start),
+                content_lo + BytePos::from_usize(end),
+            )
+        };
+
+        let tag = text.match_indices(Self::TAG).map(|(i, _)| i).find(|&i| {
+            let after = text[i + Self::TAG.len()..].chars().next();
+            after.is_some_and(char::is_whitespace)
+        })?;
+        let rest = &text[tag + Self::TAG.len()..];
+        let start = tag + Self::TAG.len() + (rest.len() - rest.trim_start().len());
+        let len = text[start..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || "_$.".contains(c)))
+            .unwrap_or(text.len() - start);
+        if len == 0 {
+            return None;
+        }
+
+        let mut path = Vec::new();
+        let mut segment_start = start;
+        for segment in text[start..start + len].split('.') {
+            if segment.is_empty() {
+                return None;
+            }
+            let end = segment_start + segment.len();
+            path.push(Ident::new(Symbol::intern(segment), span_of(segment_start, end)));
+            segment_start = end + 1;
+        }
+        Some(Self { span: span_of(tag, start + len), path })
+    }
+}
+
 /// A Solidity source file.
 pub struct SourceUnit<'ast> {
     /// The source unit's items.
     pub items: Box<'ast, IndexSlice<ItemId, [Item<'ast>]>>,
+    /// The SPDX license identifiers found in the comments of the file, in source order.
+    ///
+    /// There should be at most one, but all of them are recorded so that conflicts can be
+    /// reported.
+    pub licenses: Box<'ast, [SpdxLicense]>,
 }
 
 impl fmt::Debug for SourceUnit<'_> {
@@ -135,9 +241,92 @@ impl fmt::Debug for SourceUnit<'_> {
 }
 
 impl<'ast> SourceUnit<'ast> {
-    /// Creates a new source unit from the given items.
-    pub fn new(items: Box<'ast, [Item<'ast>]>) -> Self {
-        Self { items: IndexSlice::from_slice_mut(items) }
+    /// Creates a new source unit from the given items and license identifiers.
+    pub fn new(items: Box<'ast, [Item<'ast>]>, licenses: Box<'ast, [SpdxLicense]>) -> Self {
+        Self { items: IndexSlice::from_slice_mut(items), licenses }
+    }
+
+    /// Returns the span of the innermost node that contains `pos`.
+    ///
+    /// If multiple nodes contain `pos`, the one with the smallest span is returned. If no node
+    /// contains `pos`, such as at the end of the file, the innermost node that ends at `pos` is
+    /// returned instead.
+    pub fn span_at(&self, pos: BytePos) -> Option<Span> {
+        let mut finder = FindAt::new(pos);
+        let _ = finder.visit_source_unit(self);
+        finder.span.get()
+    }
+
+    /// Returns the identifier at `pos`.
+    ///
+    /// See [`span_at`](Self::span_at) for more details.
+    pub fn ident_at(&self, pos: BytePos) -> Option<Ident> {
+        let mut finder = FindAt::new(pos);
+        let _ = finder.visit_source_unit(self);
+        finder.ident.get()
+    }
+}
+
+/// Finds the innermost nodes at a position.
+struct FindAt {
+    pos: BytePos,
+    span: Innermost<Span>,
+    ident: Innermost<Ident>,
+}
+
+impl FindAt {
+    fn new(pos: BytePos) -> Self {
+        Self { pos, span: Innermost::default(), ident: Innermost::default() }
+    }
+}
+
+impl<'ast> Visit<'ast> for FindAt {
+    type BreakValue = Never;
+
+    fn visit_ident(&mut self, ident: &'ast Ident) -> ControlFlow<Self::BreakValue> {
+        self.ident.consider(self.pos, ident.span, *ident);
+        self.visit_span(&ident.span)
+    }
+
+    fn visit_span(&mut self, span: &'ast Span) -> ControlFlow<Self::BreakValue> {
+        self.span.consider(self.pos, *span, *span);
+        ControlFlow::Continue(())
+    }
+}
+
+/// The innermost value whose span contains a position, or that ends at it.
+struct Innermost<T> {
+    containing: Option<(Span, T)>,
+    touching: Option<(Span, T)>,
+}
+
+impl<T> Default for Innermost<T> {
+    fn default() -> Self {
+        Self { containing: None, touching: None }
+    }
+}
+
+impl<T> Innermost<T> {
+    fn consider(&mut self, pos: BytePos, span: Span, value: T) {
+        if span.is_dummy() {
+            return;
+        }
+        let slot = if span.contains_pos(pos) {
+            &mut self.containing
+        } else if span.hi() == pos {
+            &mut self.touching
+        } else {
+            return;
+        };
+        // Nodes are visited before their children, so prefer the latest one on ties.
+        let len = |span: Span| span.hi() - span.lo();
+        if slot.as_ref().is_none_or(|(best, _)| len(span) <= len(*best)) {
+            *slot = Some((span, value));
+        }
+    }
+
+    fn get(self) -> Option<T> {
+        self.containing.or(self.touching).map(|(_, value)| value)
     }
 }
 
@@ -161,5 +350,8 @@ mod tests {
         assert_no_drop::<Stmt<'_>>();
         assert_no_drop::<Item<'_>>();
         assert_no_drop::<SourceUnit<'_>>();
+        assert_no_drop::<yul::Expr<'_>>();
+        assert_no_drop::<yul::Stmt<'_>>();
+        assert_no_drop::<yul::Object<'_>>();
     }
 }
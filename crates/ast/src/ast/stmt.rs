@@ -2,7 +2,10 @@ use super::{
     yul, AstPath, Box, CallArgs, DocComments, Expr, ParameterList, PathSlice, StrLit,
     VariableDefinition,
 };
-use solar_interface::{Ident, Span};
+use crate::visit::Visit;
+use solar_data_structures::Never;
+use solar_interface::{Ident, Span, Symbol};
+use std::ops::ControlFlow;
 
 /// A block of statements.
 pub type Block<'ast> = Box<'ast, [Stmt<'ast>]>;
@@ -90,6 +93,101 @@ pub struct StmtAssembly<'ast> {
     pub block: yul::Block<'ast>,
 }
 
+impl<'ast> StmtAssembly<'ast> {
+    /// Returns the identifiers referenced in the block that are not declared in it, in order of
+    /// first use.
+    ///
+    /// These refer to Solidity declarations outside of the block, such as local or state
+    /// variables. Only the first segment of a path is recorded, so `x.slot` records `x`.
+    pub fn referenced_externals(&'ast self) -> Vec<Ident> {
+        let mut collector = ExternalRefs { scopes: Vec::new(), refs: Vec::new() };
+        let _ = collector.visit_yul_block(&self.block);
+        collector.refs
+    }
+}
+
+/// Collects the external references of a Yul block, following Yul scoping rules.
+struct ExternalRefs {
+    /// The variables declared in each enclosing scope.
+    scopes: Vec<Vec<Symbol>>,
+    refs: Vec<Ident>,
+}
+
+impl<'ast> Visit<'ast> for ExternalRefs {
+    type BreakValue = Never;
+
+    fn visit_yul_block(&mut self, block: &'ast yul::Block<'ast>) -> ControlFlow<Never> {
+        self.scopes.push(Vec::new());
+        let _ = self.walk_yul_block(block);
+        self.scopes.pop();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_yul_stmt(&mut self, stmt: &'ast yul::Stmt<'ast>) -> ControlFlow<Never> {
+        match &stmt.kind {
+            // Assigned values are evaluated before the assignment.
+            yul::StmtKind::AssignSingle(path, expr) => {
+                self.visit_yul_expr(expr)?;
+                self.visit_path(path)?;
+            }
+            yul::StmtKind::AssignMulti(paths, call) => {
+                self.visit_yul_expr_call(call)?;
+                for path in paths.iter() {
+                    self.visit_path(path)?;
+                }
+            }
+            yul::StmtKind::For { init, cond, step, body } => {
+                // Variables declared in `init` are visible in the rest of the loop.
+                self.scopes.push(Vec::new());
+                for stmt in init.iter() {
+                    self.visit_yul_stmt(stmt)?;
+                }
+                self.visit_yul_expr(cond)?;
+                self.visit_yul_block(step)?;
+                self.visit_yul_block(body)?;
+                self.scopes.pop();
+            }
+            yul::StmtKind::VarDecl(idents, expr) => {
+                // The declared variables are only visible after the declaration.
+                if let Some(expr) = expr {
+                    self.visit_yul_expr(expr)?;
+                }
+                let scope = self.scopes.last_mut().expect("no scope");
+                scope.extend(idents.iter().map(|i| i.name));
+            }
+            _ => return self.walk_yul_stmt(stmt),
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_yul_function(&mut self, function: &'ast yul::Function<'ast>) -> ControlFlow<Never> {
+        // Functions cannot access variables declared outside of them.
+        let yul::Function { name: _, parameters, returns, body } = function;
+        let outer = std::mem::take(&mut self.scopes);
+        self.scopes.push(parameters.iter().chain(returns.iter()).map(|i| i.name).collect());
+        let _ = self.visit_yul_block(body);
+        self.scopes = outer;
+        ControlFlow::Continue(())
+    }
+
+    fn visit_yul_expr_call(&mut self, call: &'ast yul::ExprCall<'ast>) -> ControlFlow<Never> {
+        // Function names are either builtins or Yul functions, never Solidity declarations.
+        for arg in call.arguments.iter() {
+            self.visit_yul_expr(arg)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_path(&mut self, path: &'ast PathSlice) -> ControlFlow<Never> {
+        let ident = *path.first();
+        let is_local = self.scopes.iter().any(|scope| scope.contains(&ident.name));
+        if !is_local && !self.refs.iter().any(|r| r.name == ident.name) {
+            self.refs.push(ident);
+        }
+        ControlFlow::Continue(())
+    }
+}
+
 /// A try statement: `try fooBar(42) returns (...) { ... } catch (...) { ... }`.
 ///
 /// Reference: <https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.tryStatement>
@@ -98,18 +196,38 @@ pub struct StmtTry<'ast> {
     /// The call expression.
     pub expr: Box<'ast, Expr<'ast>>,
     /// The list of clauses. Never empty.
+    ///
+    /// The first clause is the success clause, followed by at least one catch clause.
     pub clauses: Box<'ast, [TryCatchClause<'ast>]>,
 }
 
+impl<'ast> StmtTry<'ast> {
+    /// Returns the success clause: `returns (...) { ... }`.
+    pub fn success(&self) -> &TryCatchClause<'ast> {
+        &self.clauses[0]
+    }
+
+    /// Returns the catch clauses: `catch (...) { ... }`.
+    pub fn catches(&self) -> &[TryCatchClause<'ast>] {
+        &self.clauses[1..]
+    }
+}
+
 /// Clause of a try/catch block: `returns/catch (...) { ... }`.
 ///
 /// Includes both the successful case and the unsuccessful cases.
 /// Names are only allowed for unsuccessful cases.
 ///
+/// The parameters of a clause are only in scope in that clause's block.
+///
 /// Reference: <https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.catchClause>
 #[derive(Debug)]
 pub struct TryCatchClause<'ast> {
+    /// The name of the catch clause, such as `Error` or `Panic`. Always `None` for the success
+    /// clause.
     pub name: Option<Ident>,
+    /// The `returns` parameters of the success clause, or the parameters of a catch clause.
     pub args: ParameterList<'ast>,
+    /// The block in which `args` are in scope.
     pub block: Block<'ast>,
 }
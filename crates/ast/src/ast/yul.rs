@@ -1,7 +1,10 @@
 //! Yul AST.
 
-use super::{AstPath, Box, DocComments, Lit, StrLit};
-use solar_interface::{Ident, Span};
+use super::{AstPath, Box, DocComments, Lit, LitKind, StrKind, StrLit};
+use crate::visit::Visit;
+use solar_data_structures::Never;
+use solar_interface::{sym, Ident, Span};
+use std::ops::ControlFlow;
 
 /// A block of Yul statements: `{ ... }`.
 ///
@@ -39,6 +42,34 @@ pub struct CodeBlock<'ast> {
     pub code: Block<'ast>,
 }
 
+impl<'ast> CodeBlock<'ast> {
+    /// Returns the names of the objects and data segments referenced in the code block with the
+    /// `datasize("name")` and `dataoffset("name")` builtins, in order of appearance.
+    ///
+    /// Reference: <https://docs.soliditylang.org/en/latest/yul.html#datasize-dataoffset-datacopy>
+    pub fn data_refs(&'ast self) -> Vec<StrLit> {
+        let mut collector = DataRefs { refs: Vec::new() };
+        let _ = collector.visit_yul_block(&self.code);
+        collector.refs
+    }
+}
+
+/// Collects the names referenced by `datasize` and `dataoffset` calls.
+struct DataRefs {
+    refs: Vec<StrLit>,
+}
+
+impl<'ast> Visit<'ast> for DataRefs {
+    type BreakValue = Never;
+
+    fn visit_yul_expr_call(&mut self, call: &'ast ExprCall<'ast>) -> ControlFlow<Never> {
+        if let Some(name) = call.data_ref() {
+            self.refs.push(name);
+        }
+        self.walk_yul_expr_call(call)
+    }
+}
+
 /// A Yul `data` segment. See [`Object`].
 #[derive(Debug)]
 pub struct Data<'ast> {
@@ -177,8 +208,10 @@ pub enum ExprKind<'ast> {
     Path(AstPath<'ast>),
     /// A function call: `foo(a, b)`.
     Call(ExprCall<'ast>),
-    /// A literal.
-    Lit(&'ast mut Lit),
+    /// A literal, with an optional type suffix: `0x1234`, `true:bool`.
+    ///
+    /// Type suffixes are only used in typed Yul dialects.
+    Lit(&'ast mut Lit, Option<Ident>),
 }
 
 /// A Yul function call expression: `foo(a, b)`.
@@ -189,3 +222,80 @@ pub struct ExprCall<'ast> {
     pub name: Ident,
     pub arguments: Box<'ast, [Expr<'ast>]>,
 }
+
+impl ExprCall<'_> {
+    /// Returns the number of inputs and outputs of a call to a `verbatim_<N>i_<M>o` builtin, or
+    /// `None` if this is not a `verbatim` call.
+    ///
+    /// See [`parse_verbatim_name`].
+    pub fn verbatim_io(&self) -> Option<(u8, u8)> {
+        parse_verbatim_name(self.name.as_str())
+    }
+
+    /// Returns the object or data segment name referenced by a well-formed `datasize("name")` or
+    /// `dataoffset("name")` call, or `None` otherwise.
+    pub fn data_ref(&self) -> Option<StrLit> {
+        if !matches!(self.name.name, sym::datasize | sym::dataoffset) {
+            return None;
+        }
+        match &self.arguments[..] {
+            [Expr { kind: ExprKind::Lit(lit, None), .. }]
+                if matches!(lit.kind, LitKind::Str(StrKind::Str, _)) =>
+            {
+                Some(StrLit { span: lit.span, value: lit.symbol })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Parses the name of a `verbatim_<N>i_<M>o` builtin, returning the number of inputs `N` and
+/// outputs `M`.
+///
+/// Both counts are decimal numbers between 0 and 99, without leading zeros.
+///
+/// Reference: <https://docs.soliditylang.org/en/latest/yul.html#verbatim>
+pub fn parse_verbatim_name(name: &str) -> Option<(u8, u8)> {
+    fn count(s: &str) -> Option<u8> {
+        let valid = matches!(s.len(), 1 | 2)
+            && s.bytes().all(|b| b.is_ascii_digit())
+            && !(s.len() == 2 && s.starts_with('0'));
+        if valid {
+            s.parse().ok()
+        } else {
+            None
+        }
+    }
+
+    let (inputs, outputs) = name.strip_prefix("verbatim_")?.split_once("i_")?;
+    Some((count(inputs)?, count(outputs.strip_suffix('o')?)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbatim_names() {
+        assert_eq!(parse_verbatim_name("verbatim_0i_0o"), Some((0, 0)));
+        assert_eq!(parse_verbatim_name("verbatim_2i_1o"), Some((2, 1)));
+        assert_eq!(parse_verbatim_name("verbatim_99i_10o"), Some((99, 10)));
+        for name in [
+            "verbatim",
+            "verbatim_",
+            "verbatim_1i",
+            "verbatim_1i_",
+            "verbatim_1i_1",
+            "verbatim_i_1o",
+            "verbatim_1i_o",
+            "verbatim_01i_1o",
+            "verbatim_1i_100o",
+            "verbatim_+1i_1o",
+            "verbatim_1i_1o_",
+            "verbatim_1o_1i",
+            "Verbatim_1i_1o",
+        ] {
+            assert_eq!(parse_verbatim_name(name), None, "{name}");
+        }
+    }
+}
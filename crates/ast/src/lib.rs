@@ -12,5 +12,7 @@ pub use solar_interface as interface;
 mod ast;
 pub use ast::*;
 
+pub mod print;
+pub mod sexpr;
 pub mod token;
 pub mod visit;
@@ -0,0 +1,1045 @@
+//! AST pretty-printer.
+//!
+//! Reconstructs Solidity source code from the AST. The output is not guaranteed to preserve the
+//! original formatting, but it is guaranteed to be valid source that parses back into an
+//! equivalent AST.
+
+use crate::{
+    ast::*,
+    token::{Token, TokenKind, TokenLit},
+};
+use either::Either;
+use std::fmt::{self, Write};
+
+/// The string used for one level of indentation.
+const INDENT: &str = "    ";
+
+/// Pretty-prints a source unit.
+pub fn source_unit_to_string(source_unit: &SourceUnit<'_>) -> String {
+    Printer::new().with(|p| p.print_source_unit(source_unit))
+}
+
+/// Pretty-prints an item.
+pub fn item_to_string(item: &Item<'_>) -> String {
+    Printer::new().with(|p| p.print_item(item))
+}
+
+/// Pretty-prints a statement.
+pub fn stmt_to_string(stmt: &Stmt<'_>) -> String {
+    Printer::new().with(|p| p.print_stmt(stmt))
+}
+
+/// Pretty-prints an expression.
+pub fn expr_to_string(expr: &Expr<'_>) -> String {
+    Printer::new().with(|p| p.print_expr(expr))
+}
+
+/// Pretty-prints a type.
+pub fn ty_to_string(ty: &Type<'_>) -> String {
+    Printer::new().with(|p| p.print_ty(ty))
+}
+
+/// An AST pretty-printer.
+///
+/// Writes formatted source code into an internal buffer, which can be retrieved with
+/// [`finish`](Self::finish).
+#[derive(Debug, Default)]
+pub struct Printer {
+    out: String,
+    indent: usize,
+    at_line_start: bool,
+}
+
+impl Write for Printer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.word(s);
+        Ok(())
+    }
+}
+
+impl Printer {
+    /// Creates a new printer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consumes the printer, returning the printed source code.
+    pub fn finish(self) -> String {
+        self.out
+    }
+
+    fn with(mut self, f: impl FnOnce(&mut Self)) -> String {
+        f(&mut self);
+        self.finish()
+    }
+
+    // --- Primitives ---
+
+    fn word(&mut self, s: &str) {
+        if s.is_empty() {
+            return;
+        }
+        if self.at_line_start {
+            for _ in 0..self.indent {
+                self.out.push_str(INDENT);
+            }
+            self.at_line_start = false;
+        }
+        self.out.push_str(s);
+    }
+
+    fn display(&mut self, d: impl fmt::Display) {
+        let _ = write!(self, "{d}");
+    }
+
+    fn newline(&mut self) {
+        self.out.push('\n');
+        self.at_line_start = true;
+    }
+
+    fn commasep<T>(&mut self, items: &[T], mut f: impl FnMut(&mut Self, &T)) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.word(", ");
+            }
+            f(self, item);
+        }
+    }
+
+    /// Prints `{`, the lines printed by `f` at one more level of indentation, and `}`.
+    fn braced<T>(&mut self, items: &[T], mut f: impl FnMut(&mut Self, &T)) {
+        if items.is_empty() {
+            self.word("{}");
+            return;
+        }
+        self.word("{");
+        self.indent += 1;
+        for item in items {
+            self.newline();
+            f(self, item);
+        }
+        self.indent -= 1;
+        self.newline();
+        self.word("}");
+    }
+
+    // --- Items ---
+
+    /// Prints a source unit.
+    pub fn print_source_unit(&mut self, source_unit: &SourceUnit<'_>) {
+        self.print_items(&source_unit.items.raw);
+        if !source_unit.items.is_empty() {
+            self.newline();
+        }
+    }
+
+    fn print_items(&mut self, items: &[Item<'_>]) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.newline();
+                if needs_blank_line(&items[i - 1], item) {
+                    self.newline();
+                }
+            }
+            self.print_item(item);
+        }
+    }
+
+    /// Prints an item.
+    pub fn print_item(&mut self, item: &Item<'_>) {
+        self.print_docs(&item.docs);
+        match &item.kind {
+            ItemKind::Pragma(pragma) => self.print_pragma(pragma),
+            ItemKind::Import(import) => self.print_import(import),
+            ItemKind::Using(using) => self.print_using(using),
+            ItemKind::Contract(contract) => self.print_contract(contract),
+            ItemKind::Function(function) => self.print_function(function),
+            ItemKind::Variable(var) => {
+                self.print_var(var);
+                self.word(";");
+            }
+            ItemKind::Struct(ItemStruct { name, fields }) => {
+                self.word("struct ");
+                self.display(name);
+                self.word(" ");
+                self.braced(fields, |this, field| {
+                    this.print_var(field);
+                    this.word(";");
+                });
+            }
+            ItemKind::Enum(ItemEnum { name, variants }) => {
+                self.word("enum ");
+                self.display(name);
+                self.word(" { ");
+                self.commasep(variants, |this, variant| this.display(variant));
+                self.word(" }");
+            }
+            ItemKind::Udvt(ItemUdvt { name, ty }) => {
+                self.word("type ");
+                self.display(name);
+                self.word(" is ");
+                self.print_ty(ty);
+                self.word(";");
+            }
+            ItemKind::Error(ItemError { name, parameters }) => {
+                self.word("error ");
+                self.display(name);
+                self.print_parameter_list(parameters);
+                self.word(";");
+            }
+            ItemKind::Event(ItemEvent { name, parameters, anonymous }) => {
+                self.word("event ");
+                self.display(name);
+                self.print_parameter_list(parameters);
+                if *anonymous {
+                    self.word(" anonymous");
+                }
+                self.word(";");
+            }
+        }
+    }
+
+    fn print_docs(&mut self, docs: &DocComments<'_>) {
+        for doc in docs.iter() {
+            match doc.kind {
+                CommentKind::Line => {
+                    self.word("///");
+                    self.display(doc.symbol);
+                }
+                CommentKind::Block => {
+                    self.word("/**");
+                    self.display(doc.symbol);
+                    self.word("*/");
+                }
+            }
+            self.newline();
+        }
+    }
+
+    fn print_pragma(&mut self, pragma: &PragmaDirective<'_>) {
+        self.word("pragma ");
+        match &pragma.tokens {
+            PragmaTokens::Version(name, req) => {
+                self.display(name);
+                self.word(" ");
+                self.display(req);
+            }
+            PragmaTokens::Custom(name, value) => {
+                self.print_ident_or_str_lit(name);
+                if let Some(value) = value {
+                    self.word(" ");
+                    self.print_ident_or_str_lit(value);
+                }
+            }
+            PragmaTokens::Verbatim(tokens) => {
+                for (i, token) in tokens.iter().enumerate() {
+                    if i > 0 {
+                        self.word(" ");
+                    }
+                    self.print_token(token);
+                }
+            }
+        }
+        self.word(";");
+    }
+
+    fn print_ident_or_str_lit(&mut self, x: &IdentOrStrLit) {
+        match x {
+            IdentOrStrLit::Ident(ident) => self.display(ident),
+            IdentOrStrLit::StrLit(lit) => self.print_str_lit(lit),
+        }
+    }
+
+    fn print_token(&mut self, token: &Token) {
+        match token.kind {
+            TokenKind::Literal(kind, symbol) => self.display(TokenLit::new(kind, symbol)),
+            ref kind => self.word(kind.as_str()),
+        }
+    }
+
+    fn print_import(&mut self, import: &ImportDirective<'_>) {
+        self.word("import ");
+        match &import.items {
+            ImportItems::Plain(alias) => {
+                self.print_str_lit(&import.path);
+                self.print_alias(alias);
+            }
+            ImportItems::Aliases(aliases) => {
+                self.word("{");
+                self.commasep(aliases, |this, (name, alias)| {
+                    this.display(name);
+                    this.print_alias(alias);
+                });
+                self.word("} from ");
+                self.print_str_lit(&import.path);
+            }
+            ImportItems::Glob(alias) => {
+                self.word("*");
+                self.print_alias(alias);
+                self.word(" from ");
+                self.print_str_lit(&import.path);
+            }
+        }
+        self.word(";");
+    }
+
+    fn print_alias(&mut self, alias: &Option<Ident>) {
+        if let Some(alias) = alias {
+            self.word(" as ");
+            self.display(alias);
+        }
+    }
+
+    fn print_using(&mut self, using: &UsingDirective<'_>) {
+        self.word("using ");
+        match &using.list {
+            UsingList::Single(path) => self.display(path),
+            UsingList::Multiple(paths) => {
+                self.word("{");
+                self.commasep(paths, |this, (path, op)| {
+                    this.display(path);
+                    if let Some(op) = op {
+                        this.word(" as ");
+                        this.word(match op.to_op() {
+                            Either::Left(op) => op.to_str(),
+                            Either::Right(op) => op.to_str(),
+                        });
+                    }
+                });
+                self.word("}");
+            }
+        }
+        self.word(" for ");
+        match &using.ty {
+            Some(ty) => self.print_ty(ty),
+            None => self.word("*"),
+        }
+        if using.global {
+            self.word(" global");
+        }
+        self.word(";");
+    }
+
+    fn print_contract(&mut self, contract: &ItemContract<'_>) {
+        self.word(contract.kind.to_str());
+        self.word(" ");
+        self.display(contract.name);
+        if !contract.bases.is_empty() {
+            self.word(" is ");
+            self.commasep(contract.bases, Self::print_modifier);
+        }
+        self.word(" ");
+        if contract.body.is_empty() {
+            self.word("{}");
+            return;
+        }
+        self.word("{");
+        self.indent += 1;
+        self.newline();
+        self.print_items(contract.body);
+        self.indent -= 1;
+        self.newline();
+        self.word("}");
+    }
+
+    fn print_function(&mut self, function: &ItemFunction<'_>) {
+        let FunctionHeader {
+            name,
+            parameters,
+            visibility,
+            state_mutability,
+            modifiers,
+            virtual_,
+            override_,
+            returns,
+        } = &function.header;
+        self.word(function.kind.to_str());
+        if let Some(name) = name {
+            self.word(" ");
+            self.display(name);
+        }
+        if !function.kind.is_modifier() || !parameters.is_empty() {
+            self.print_parameter_list(parameters);
+        }
+        if let Some(visibility) = visibility {
+            self.word(" ");
+            self.word(visibility.to_str());
+        }
+        if !state_mutability.is_non_payable() {
+            self.word(" ");
+            self.word(state_mutability.to_str());
+        }
+        for modifier in modifiers.iter() {
+            self.word(" ");
+            self.print_modifier(modifier);
+        }
+        if *virtual_ {
+            self.word(" virtual");
+        }
+        if let Some(override_) = override_ {
+            self.word(" ");
+            self.print_override(override_);
+        }
+        if !returns.is_empty() {
+            self.word(" returns ");
+            self.print_parameter_list(returns);
+        }
+        match &function.body {
+            Some(body) => {
+                self.word(" ");
+                self.print_block(body);
+            }
+            None => self.word(";"),
+        }
+    }
+
+    fn print_modifier(&mut self, modifier: &Modifier<'_>) {
+        self.display(&modifier.name);
+        if !modifier.arguments.is_empty() {
+            self.print_call_args(&modifier.arguments);
+        }
+    }
+
+    fn print_override(&mut self, override_: &Override<'_>) {
+        self.word("override");
+        if !override_.paths.is_empty() {
+            self.word("(");
+            self.commasep(override_.paths, |this, path| this.display(path));
+            self.word(")");
+        }
+    }
+
+    fn print_parameter_list(&mut self, parameters: &[VariableDefinition<'_>]) {
+        self.word("(");
+        self.commasep(parameters, Self::print_var);
+        self.word(")");
+    }
+
+    fn print_var(&mut self, var: &VariableDefinition<'_>) {
+        let VariableDefinition {
+            span: _,
+            ty,
+            visibility,
+            mutability,
+            data_location,
+            override_,
+            indexed,
+            name,
+            initializer,
+        } = var;
+        self.print_ty(ty);
+        if let Some(data_location) = data_location {
+            self.word(" ");
+            self.word(data_location.to_str());
+        }
+        if let Some(visibility) = visibility {
+            self.word(" ");
+            self.word(visibility.to_str());
+        }
+        if let Some(mutability) = mutability {
+            self.word(" ");
+            self.word(mutability.to_str());
+        }
+        if let Some(override_) = override_ {
+            self.word(" ");
+            self.print_override(override_);
+        }
+        if *indexed {
+            self.word(" indexed");
+        }
+        if let Some(name) = name {
+            self.word(" ");
+            self.display(name);
+        }
+        if let Some(initializer) = initializer {
+            self.word(" = ");
+            self.print_expr(initializer);
+        }
+    }
+
+    // --- Statements ---
+
+    /// Prints a block of statements.
+    pub fn print_block(&mut self, block: &[Stmt<'_>]) {
+        self.braced(block, Self::print_stmt);
+    }
+
+    /// Prints a statement.
+    pub fn print_stmt(&mut self, stmt: &Stmt<'_>) {
+        self.print_docs(&stmt.docs);
+        match &stmt.kind {
+            StmtKind::Assembly(StmtAssembly { dialect, flags, block }) => {
+                self.word("assembly ");
+                if let Some(dialect) = dialect {
+                    self.print_str_lit(dialect);
+                    self.word(" ");
+                }
+                if !flags.is_empty() {
+                    self.word("(");
+                    self.commasep(flags, Self::print_str_lit);
+                    self.word(") ");
+                }
+                self.print_yul_block(block);
+            }
+            StmtKind::DeclSingle(..) | StmtKind::DeclMulti(..) | StmtKind::Expr(_) => {
+                self.print_simple_stmt_kind(&stmt.kind);
+                self.word(";");
+            }
+            StmtKind::Block(block) => self.print_block(block),
+            StmtKind::Break => self.word("break;"),
+            StmtKind::Continue => self.word("continue;"),
+            StmtKind::DoWhile(body, cond) => {
+                self.word("do");
+                let braced = self.print_stmt_body(body);
+                if braced {
+                    self.word(" ");
+                } else {
+                    self.newline();
+                }
+                self.word("while (");
+                self.print_expr(cond);
+                self.word(");");
+            }
+            StmtKind::Emit(path, args) => {
+                self.word("emit ");
+                self.display(path);
+                self.print_call_args(args);
+                self.word(";");
+            }
+            StmtKind::For { init, cond, next, body } => {
+                self.word("for (");
+                if let Some(init) = init {
+                    self.print_simple_stmt_kind(&init.kind);
+                }
+                self.word(";");
+                if let Some(cond) = cond {
+                    self.word(" ");
+                    self.print_expr(cond);
+                }
+                self.word(";");
+                if let Some(next) = next {
+                    self.word(" ");
+                    self.print_expr(next);
+                }
+                self.word(")");
+                self.print_stmt_body(body);
+            }
+            StmtKind::If(cond, then, else_) => {
+                self.word("if (");
+                self.print_expr(cond);
+                self.word(")");
+                let braced = self.print_stmt_body(then);
+                if let Some(else_) = else_ {
+                    if braced {
+                        self.word(" ");
+                    } else {
+                        self.newline();
+                    }
+                    self.word("else");
+                    if else_.docs.is_empty() && matches!(else_.kind, StmtKind::If(..)) {
+                        self.word(" ");
+                        self.print_stmt(else_);
+                    } else {
+                        self.print_stmt_body(else_);
+                    }
+                }
+            }
+            StmtKind::Return(expr) => {
+                self.word("return");
+                if let Some(expr) = expr {
+                    self.word(" ");
+                    self.print_expr(expr);
+                }
+                self.word(";");
+            }
+            StmtKind::Revert(path, args) => {
+                self.word("revert ");
+                self.display(path);
+                self.print_call_args(args);
+                self.word(";");
+            }
+            StmtKind::Try(StmtTry { expr, clauses }) => {
+                self.word("try ");
+                self.print_expr(expr);
+                for (i, TryCatchClause { name, args, block }) in clauses.iter().enumerate() {
+                    if i == 0 {
+                        if !args.is_empty() {
+                            self.word(" returns ");
+                            self.print_parameter_list(args);
+                        }
+                    } else {
+                        self.word(" catch");
+                        if let Some(name) = name {
+                            self.word(" ");
+                            self.display(name);
+                        }
+                        if !args.is_empty() {
+                            if name.is_none() {
+                                self.word(" ");
+                            }
+                            self.print_parameter_list(args);
+                        }
+                    }
+                    self.word(" ");
+                    self.print_block(block);
+                }
+            }
+            StmtKind::UncheckedBlock(block) => {
+                self.word("unchecked ");
+                self.print_block(block);
+            }
+            StmtKind::While(cond, body) => {
+                self.word("while (");
+                self.print_expr(cond);
+                self.word(")");
+                self.print_stmt_body(body);
+            }
+            StmtKind::Placeholder => self.word("_;"),
+        }
+    }
+
+    /// Prints a statement that can appear in a `for` loop initializer, without the trailing
+    /// semicolon.
+    fn print_simple_stmt_kind(&mut self, kind: &StmtKind<'_>) {
+        match kind {
+            StmtKind::DeclSingle(var) => self.print_var(var),
+            StmtKind::DeclMulti(vars, expr) => {
+                self.word("(");
+                self.commasep(vars, |this, var| {
+                    if let Some(var) = var {
+                        this.print_var(var);
+                    }
+                });
+                self.word(") = ");
+                self.print_expr(expr);
+            }
+            StmtKind::Expr(expr) => self.print_expr(expr),
+            _ => unreachable!("not a simple statement: {kind:?}"),
+        }
+    }
+
+    /// Prints the body of a control flow statement. Blocks are printed on the same line, other
+    /// statements on the next line with one more level of indentation.
+    ///
+    /// Returns `true` if the body was printed as a block.
+    fn print_stmt_body(&mut self, stmt: &Stmt<'_>) -> bool {
+        match &stmt.kind {
+            StmtKind::Block(block) if stmt.docs.is_empty() => {
+                self.word(" ");
+                self.print_block(block);
+                true
+            }
+            _ => {
+                self.indent += 1;
+                self.newline();
+                self.print_stmt(stmt);
+                self.indent -= 1;
+                false
+            }
+        }
+    }
+
+    // --- Expressions ---
+
+    /// Prints an expression.
+    pub fn print_expr(&mut self, expr: &Expr<'_>) {
+        match &expr.kind {
+            ExprKind::Array(exprs) => {
+                self.word("[");
+                self.commasep(exprs, |this, expr| this.print_expr(expr));
+                self.word("]");
+            }
+            ExprKind::Assign(lhs, op, rhs) => {
+                self.print_expr(lhs);
+                self.word(" ");
+                if let Some(op) = op {
+                    self.word(op.kind.to_str());
+                }
+                self.word("= ");
+                self.print_expr(rhs);
+            }
+            ExprKind::Binary(lhs, op, rhs) => {
+                self.print_expr(lhs);
+                self.word(" ");
+                self.word(op.kind.to_str());
+                self.word(" ");
+                self.print_expr(rhs);
+            }
+            ExprKind::Call(callee, args) => {
+                self.print_expr(callee);
+                self.print_call_args(args);
+            }
+            ExprKind::CallOptions(callee, options) => {
+                self.print_expr(callee);
+                self.word("{");
+                self.print_named_args(options);
+                self.word("}");
+            }
+            ExprKind::Delete(expr) => {
+                self.word("delete ");
+                self.print_expr(expr);
+            }
+            ExprKind::Ident(ident) => self.display(ident),
+            ExprKind::Index(expr, index) => {
+                self.print_expr(expr);
+                self.word("[");
+                match index {
+                    IndexKind::Index(index) => {
+                        if let Some(index) = index {
+                            self.print_expr(index);
+                        }
+                    }
+                    IndexKind::Range(start, end) => {
+                        if let Some(start) = start {
+                            self.print_expr(start);
+                        }
+                        self.word(":");
+                        if let Some(end) = end {
+                            self.print_expr(end);
+                        }
+                    }
+                }
+                self.word("]");
+            }
+            ExprKind::Lit(lit, sub) => {
+                self.print_lit(lit);
+                if let Some(sub) = sub {
+                    self.word(" ");
+                    self.word(sub.to_str());
+                }
+            }
+            ExprKind::Member(expr, member) => {
+                self.print_expr(expr);
+                self.word(".");
+                self.display(member);
+            }
+            ExprKind::New(ty) => {
+                self.word("new ");
+                self.print_ty(ty);
+            }
+            ExprKind::Payable(args) => {
+                self.word("payable");
+                self.print_call_args(args);
+            }
+            ExprKind::Ternary(cond, then, else_) => {
+                self.print_expr(cond);
+                self.word(" ? ");
+                self.print_expr(then);
+                self.word(" : ");
+                self.print_expr(else_);
+            }
+            ExprKind::Tuple(exprs) => {
+                self.word("(");
+                self.commasep(exprs, |this, expr| {
+                    if let Some(expr) = expr {
+                        this.print_expr(expr);
+                    }
+                });
+                self.word(")");
+            }
+            ExprKind::TypeCall(ty) => {
+                self.word("type(");
+                self.print_ty(ty);
+                self.word(")");
+            }
+            ExprKind::Type(ty) => self.print_ty(ty),
+            ExprKind::Unary(op, expr) => {
+                if op.kind.is_prefix() {
+                    self.word(op.kind.to_str());
+                    // Don't glue `-` and `--` together.
+                    if let ExprKind::Unary(inner, _) = &expr.kind {
+                        let is_minus =
+                            |op: UnOpKind| matches!(op, UnOpKind::Neg | UnOpKind::PreDec);
+                        if is_minus(op.kind) && is_minus(inner.kind) {
+                            self.word(" ");
+                        }
+                    }
+                    self.print_expr(expr);
+                } else {
+                    self.print_expr(expr);
+                    self.word(op.kind.to_str());
+                }
+            }
+        }
+    }
+
+    fn print_call_args(&mut self, args: &CallArgs<'_>) {
+        self.word("(");
+        match args {
+            CallArgs::Unnamed(exprs) => self.commasep(exprs, |this, expr| this.print_expr(expr)),
+            CallArgs::Named(args) => {
+                self.word("{");
+                self.print_named_args(args);
+                self.word("}");
+            }
+        }
+        self.word(")");
+    }
+
+    fn print_named_args(&mut self, args: &[NamedArg<'_>]) {
+        self.commasep(args, |this, NamedArg { name, value }| {
+            this.display(name);
+            this.word(": ");
+            this.print_expr(value);
+        });
+    }
+
+    // --- Types ---
+
+    /// Prints a type.
+    pub fn print_ty(&mut self, ty: &Type<'_>) {
+        match &ty.kind {
+            TypeKind::Elementary(ty) => self.display(ty),
+            TypeKind::Array(array) => {
+                self.print_ty(&array.element);
+                self.word("[");
+                if let Some(size) = &array.size {
+                    self.print_expr(size);
+                }
+                self.word("]");
+            }
+            TypeKind::Function(f) => {
+                self.word("function");
+                self.print_parameter_list(f.parameters);
+                if let Some(visibility) = f.visibility {
+                    self.word(" ");
+                    self.word(visibility.to_str());
+                }
+                if !f.state_mutability.is_non_payable() {
+                    self.word(" ");
+                    self.word(f.state_mutability.to_str());
+                }
+                if !f.returns.is_empty() {
+                    self.word(" returns ");
+                    self.print_parameter_list(f.returns);
+                }
+            }
+            TypeKind::Mapping(mapping) => {
+                self.word("mapping(");
+                self.print_ty(&mapping.key);
+                if let Some(key_name) = mapping.key_name {
+                    self.word(" ");
+                    self.display(key_name);
+                }
+                self.word(" => ");
+                self.print_ty(&mapping.value);
+                if let Some(value_name) = mapping.value_name {
+                    self.word(" ");
+                    self.display(value_name);
+                }
+                self.word(")");
+            }
+            TypeKind::Custom(path) => self.display(path),
+        }
+    }
+
+    // --- Literals ---
+
+    /// Prints a literal.
+    pub fn print_lit(&mut self, lit: &Lit) {
+        match &lit.kind {
+            // The symbol only contains the first string of a concatenation, so print the value.
+            LitKind::Str(kind, value) => {
+                match kind {
+                    StrKind::Str => {}
+                    StrKind::Unicode => self.word("unicode"),
+                    StrKind::Hex => self.word("hex"),
+                }
+                self.word("\"");
+                match kind {
+                    StrKind::Hex => self.word(&alloy_primitives::hex::encode(value)),
+                    StrKind::Unicode if std::str::from_utf8(value).is_ok() => {
+                        let s = std::str::from_utf8(value).unwrap();
+                        for c in s.chars() {
+                            if c.is_ascii() {
+                                self.escape_byte(c as u8);
+                            } else {
+                                let mut buf = [0; 4];
+                                self.word(c.encode_utf8(&mut buf));
+                            }
+                        }
+                    }
+                    StrKind::Str | StrKind::Unicode => {
+                        for &b in value.iter() {
+                            self.escape_byte(b);
+                        }
+                    }
+                }
+                self.word("\"");
+            }
+            LitKind::Number(_)
+            | LitKind::Rational(_)
+            | LitKind::Address(_)
+            | LitKind::Bool(_)
+            | LitKind::Err(_) => self.display(lit.symbol),
+        }
+    }
+
+    fn escape_byte(&mut self, b: u8) {
+        match b {
+            b'"' => self.word("\\\""),
+            b'\\' => self.word("\\\\"),
+            b'\n' => self.word("\\n"),
+            b'\r' => self.word("\\r"),
+            b'\t' => self.word("\\t"),
+            0x20..=0x7e => self.word(std::str::from_utf8(&[b]).unwrap()),
+            _ => {
+                let _ = write!(self, "\\x{b:02x}");
+            }
+        }
+    }
+
+    fn print_str_lit(&mut self, lit: &StrLit) {
+        // The value is the raw, unescaped contents, which may contain unescaped double quotes if
+        // the literal was single-quoted.
+        let quote = if lit.value.as_str().contains('"') { "'" } else { "\"" };
+        self.word(quote);
+        self.display(lit.value);
+        self.word(quote);
+    }
+
+    // --- Yul ---
+
+    /// Prints a Yul object.
+    pub fn print_yul_object(&mut self, object: &yul::Object<'_>) {
+        let yul::Object { docs, span: _, name, code, children, data } = object;
+        self.print_docs(docs);
+        self.word("object ");
+        self.print_str_lit(name);
+        self.word(" {");
+        self.indent += 1;
+        self.newline();
+        self.word("code ");
+        self.print_yul_block(code.code);
+        for child in children.iter() {
+            self.newline();
+            self.print_yul_object(child);
+        }
+        for yul::Data { span: _, name, data } in data.iter() {
+            self.newline();
+            self.word("data ");
+            self.print_str_lit(name);
+            self.word(" ");
+            self.print_lit(data);
+        }
+        self.indent -= 1;
+        self.newline();
+        self.word("}");
+    }
+
+    /// Prints a Yul block.
+    pub fn print_yul_block(&mut self, block: &[yul::Stmt<'_>]) {
+        self.braced(block, Self::print_yul_stmt);
+    }
+
+    /// Prints a Yul statement.
+    pub fn print_yul_stmt(&mut self, stmt: &yul::Stmt<'_>) {
+        self.print_docs(&stmt.docs);
+        match &stmt.kind {
+            yul::StmtKind::Block(block) => self.print_yul_block(block),
+            yul::StmtKind::AssignSingle(path, expr) => {
+                self.display(path);
+                self.word(" := ");
+                self.print_yul_expr(expr);
+            }
+            yul::StmtKind::AssignMulti(paths, call) => {
+                self.commasep(paths, |this, path| this.display(path));
+                self.word(" := ");
+                self.print_yul_call(call);
+            }
+            yul::StmtKind::Expr(call) => self.print_yul_call(call),
+            yul::StmtKind::If(cond, block) => {
+                self.word("if ");
+                self.print_yul_expr(cond);
+                self.word(" ");
+                self.print_yul_block(block);
+            }
+            yul::StmtKind::For { init, cond, step, body } => {
+                self.word("for ");
+                self.print_yul_block(init);
+                self.word(" ");
+                self.print_yul_expr(cond);
+                self.word(" ");
+                self.print_yul_block(step);
+                self.word(" ");
+                self.print_yul_block(body);
+            }
+            yul::StmtKind::Switch(yul::StmtSwitch { selector, branches, default_case }) => {
+                self.word("switch ");
+                self.print_yul_expr(selector);
+                for yul::StmtSwitchCase { constant, body } in branches.iter() {
+                    self.newline();
+                    self.word("case ");
+                    self.print_lit(constant);
+                    self.word(" ");
+                    self.print_yul_block(body);
+                }
+                if let Some(default_case) = default_case {
+                    self.newline();
+                    self.word("default ");
+                    self.print_yul_block(default_case);
+                }
+            }
+            yul::StmtKind::Leave => self.word("leave"),
+            yul::StmtKind::Break => self.word("break"),
+            yul::StmtKind::Continue => self.word("continue"),
+            yul::StmtKind::FunctionDef(yul::Function { name, parameters, returns, body }) => {
+                self.word("function ");
+                self.display(name);
+                self.word("(");
+                self.commasep(parameters, |this, param| this.display(param));
+                self.word(")");
+                if !returns.is_empty() {
+                    self.word(" -> ");
+                    self.commasep(returns, |this, ret| this.display(ret));
+                }
+                self.word(" ");
+                self.print_yul_block(body);
+            }
+            yul::StmtKind::VarDecl(names, expr) => {
+                self.word("let ");
+                self.commasep(names, |this, name| this.display(name));
+                if let Some(expr) = expr {
+                    self.word(" := ");
+                    self.print_yul_expr(expr);
+                }
+            }
+        }
+    }
+
+    /// Prints a Yul expression.
+    pub fn print_yul_expr(&mut self, expr: &yul::Expr<'_>) {
+        match &expr.kind {
+            yul::ExprKind::Path(path) => self.display(path),
+            yul::ExprKind::Call(call) => self.print_yul_call(call),
+            yul::ExprKind::Lit(lit, ty) => {
+                self.print_lit(lit);
+                if let Some(ty) = ty {
+                    self.word(":");
+                    self.display(ty);
+                }
+            }
+        }
+    }
+
+    fn print_yul_call(&mut self, call: &yul::ExprCall<'_>) {
+        self.display(call.name);
+        self.word("(");
+        self.commasep(call.arguments, Self::print_yul_expr);
+        self.word(")");
+    }
+}
+
+/// Returns `true` if a blank line should separate the two consecutive items.
+fn needs_blank_line<'ast>(prev: &Item<'ast>, next: &Item<'ast>) -> bool {
+    let is_multiline = |item: &Item<'_>| {
+        !item.docs.is_empty()
+            || matches!(
+                item.kind,
+                ItemKind::Contract(_) | ItemKind::Function(_) | ItemKind::Struct(_)
+            )
+    };
+    is_multiline(prev)
+        || is_multiline(next)
+        || std::mem::discriminant(&prev.kind) != std::mem::discriminant(&next.kind)
+}
@@ -0,0 +1,421 @@
+//! AST S-expression printer.
+//!
+//! Prints the structure of the AST as an indented S-expression, such as
+//! `(source-unit (contract C (function f (params) (block))))`, which is compact and easy to diff in
+//! golden tests. Types, literals and paths are printed as single atoms.
+
+use crate::{ast::*, print, visit::Visit};
+use solar_data_structures::Never;
+use solar_interface::{BytePos, Ident, Span};
+use std::{fmt::Write, ops::ControlFlow};
+
+/// Prints a source unit as an S-expression.
+///
+/// If `spans` is the start position of the source unit's file, the byte range of each node
+/// relative to it is printed after its label: `(block@10..20)`.
+pub fn source_unit_to_sexpr(source_unit: &SourceUnit<'_>, spans: Option<BytePos>) -> String {
+    let mut printer = SExprPrinter { out: String::new(), depth: 0, spans, next_span: None };
+    let _ = printer.visit_source_unit(source_unit);
+    printer.out.push('\n');
+    printer.out
+}
+
+struct SExprPrinter {
+    out: String,
+    depth: usize,
+    /// The start position of the file, if spans are printed.
+    spans: Option<BytePos>,
+    /// The span of the item being visited, used for the next opened node.
+    next_span: Option<Span>,
+}
+
+impl SExprPrinter {
+    /// Prints `(label`, followed by the output of `f`, and `)`.
+    fn node(&mut self, label: &str, span: Option<Span>, f: impl FnOnce(&mut Self)) {
+        if !self.out.is_empty() {
+            self.out.push('\n');
+            for _ in 0..self.depth {
+                self.out.push_str("  ");
+            }
+        }
+        self.out.push('(');
+        self.out.push_str(label);
+        if let (Some(span), Some(start)) = (span.or(self.next_span.take()), self.spans) {
+            let _ = write!(self.out, "@{}..{}", (span.lo() - start).0, (span.hi() - start).0);
+        }
+        self.depth += 1;
+        f(self);
+        self.depth -= 1;
+        self.out.push(')');
+    }
+
+    /// Prints a single atom, quoting it if it would not be parsed back as one.
+    fn atom(&mut self, s: &str) {
+        self.out.push(' ');
+        if s.is_empty() || s.contains(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | '"')) {
+            let _ = write!(self.out, "{s:?}");
+        } else {
+            self.out.push_str(s);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for SExprPrinter {
+    type BreakValue = Never;
+
+    fn visit_source_unit(&mut self, source_unit: &SourceUnit<'ast>) -> ControlFlow<Never> {
+        self.node("source-unit", None, |this| {
+            let _ = this.walk_source_unit(source_unit);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_item(&mut self, item: &'ast Item<'ast>) -> ControlFlow<Never> {
+        self.next_span = Some(item.span);
+        if let ItemKind::Pragma(_) = item.kind {
+            let pragma = print::item_to_string(item);
+            let pragma = pragma.trim_start_matches("pragma ").trim_end_matches(';');
+            self.node("pragma", None, |this| this.atom(pragma));
+            return ControlFlow::Continue(());
+        }
+        self.walk_item(item)
+    }
+
+    fn visit_import_directive(
+        &mut self,
+        import: &'ast ImportDirective<'ast>,
+    ) -> ControlFlow<Never> {
+        self.node("import", None, |this| {
+            this.atom(&format!("{:?}", import.path.value.as_str()));
+            let _ = this.walk_import_directive(import);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_using_directive(&mut self, using: &'ast UsingDirective<'ast>) -> ControlFlow<Never> {
+        self.node("using", None, |this| {
+            let _ = this.walk_using_directive(using);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_item_contract(&mut self, contract: &'ast ItemContract<'ast>) -> ControlFlow<Never> {
+        let ItemContract { kind, name, bases, body } = contract;
+        self.node(&kind.to_str().replace(' ', "-"), None, |this| {
+            let _ = this.visit_ident(name);
+            for base in bases.iter() {
+                this.node("is", None, |this| {
+                    let _ = this.walk_modifier(base);
+                });
+            }
+            for item in body.iter() {
+                let _ = this.visit_item(item);
+            }
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_item_function(&mut self, func: &'ast ItemFunction<'ast>) -> ControlFlow<Never> {
+        self.node(func.kind.to_str(), None, |this| {
+            let _ = this.walk_item_function(func);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_function_header(&mut self, header: &'ast FunctionHeader<'ast>) -> ControlFlow<Never> {
+        let FunctionHeader {
+            name,
+            parameters,
+            visibility,
+            state_mutability,
+            modifiers,
+            virtual_,
+            override_,
+            returns,
+        } = header;
+        if let Some(name) = name {
+            self.visit_ident(name)?;
+        }
+        if let Some(visibility) = visibility {
+            self.atom(visibility.to_str());
+        }
+        if !state_mutability.is_non_payable() {
+            self.atom(state_mutability.to_str());
+        }
+        if *virtual_ {
+            self.atom("virtual");
+        }
+        if override_.is_some() {
+            self.atom("override");
+        }
+        self.visit_parameter_list(parameters)?;
+        for modifier in modifiers.iter() {
+            self.visit_modifier(modifier)?;
+        }
+        if !returns.is_empty() {
+            self.node("returns", None, |this| {
+                let _ = this.walk_parameter_list(returns);
+            });
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_variable_definition(
+        &mut self,
+        var: &'ast VariableDefinition<'ast>,
+    ) -> ControlFlow<Never> {
+        self.node("var", Some(var.span), |this| {
+            let _ = this.walk_variable_definition(var);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_item_struct(&mut self, strukt: &'ast ItemStruct<'ast>) -> ControlFlow<Never> {
+        self.node("struct", None, |this| {
+            let _ = this.walk_item_struct(strukt);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_item_enum(&mut self, enum_: &'ast ItemEnum<'ast>) -> ControlFlow<Never> {
+        self.node("enum", None, |this| {
+            let _ = this.walk_item_enum(enum_);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_item_udvt(&mut self, udvt: &'ast ItemUdvt<'ast>) -> ControlFlow<Never> {
+        self.node("type", None, |this| {
+            let _ = this.walk_item_udvt(udvt);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_item_error(&mut self, error: &'ast ItemError<'ast>) -> ControlFlow<Never> {
+        self.node("error", None, |this| {
+            let _ = this.walk_item_error(error);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_item_event(&mut self, event: &'ast ItemEvent<'ast>) -> ControlFlow<Never> {
+        self.node("event", None, |this| {
+            let _ = this.walk_item_event(event);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_ty(&mut self, ty: &'ast Type<'ast>) -> ControlFlow<Never> {
+        self.atom(&print::ty_to_string(ty));
+        ControlFlow::Continue(())
+    }
+
+    fn visit_modifier(&mut self, modifier: &'ast Modifier<'ast>) -> ControlFlow<Never> {
+        self.node("modifier", None, |this| {
+            let _ = this.walk_modifier(modifier);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_call_args(&mut self, args: &'ast CallArgs<'ast>) -> ControlFlow<Never> {
+        if !args.is_empty() {
+            self.node("args", None, |this| {
+                let _ = this.walk_call_args(args);
+            });
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_stmt(&mut self, stmt: &'ast Stmt<'ast>) -> ControlFlow<Never> {
+        let label = match &stmt.kind {
+            // These are already printed as a single node.
+            StmtKind::Block(_) | StmtKind::DeclSingle(_) | StmtKind::Expr(_) => {
+                return self.walk_stmt(stmt);
+            }
+            StmtKind::Assembly(_) => "assembly",
+            StmtKind::DeclMulti(..) => "var-multi",
+            StmtKind::Break => "break",
+            StmtKind::Continue => "continue",
+            StmtKind::DoWhile(..) => "do-while",
+            StmtKind::Emit(..) => "emit",
+            StmtKind::For { .. } => "for",
+            StmtKind::If(..) => "if",
+            StmtKind::Return(_) => "return",
+            StmtKind::Revert(..) => "revert",
+            StmtKind::Try(_) => "try",
+            StmtKind::UncheckedBlock(_) => "unchecked",
+            StmtKind::While(..) => "while",
+            StmtKind::Placeholder => "placeholder",
+        };
+        self.node(label, Some(stmt.span), |this| {
+            let _ = this.walk_stmt(stmt);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_try_catch_clause(&mut self, catch: &'ast TryCatchClause<'ast>) -> ControlFlow<Never> {
+        self.node("catch", None, |this| {
+            let _ = this.walk_try_catch_clause(catch);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_block(&mut self, block: &'ast Block<'ast>) -> ControlFlow<Never> {
+        self.node("block", None, |this| {
+            let _ = this.walk_block(block);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_expr(&mut self, expr: &'ast Expr<'ast>) -> ControlFlow<Never> {
+        let span = Some(expr.span);
+        match &expr.kind {
+            ExprKind::Ident(ident) => return self.visit_ident(ident),
+            ExprKind::Lit(lit, sub) => {
+                self.visit_lit(lit)?;
+                if let Some(sub) = sub {
+                    self.atom(sub.to_str());
+                }
+                return ControlFlow::Continue(());
+            }
+            ExprKind::Assign(_, op, _) => {
+                let op = op.map(|op| format!("{}=", op.kind.to_str()));
+                self.node("assign", span, |this| {
+                    this.atom(op.as_deref().unwrap_or("="));
+                    let _ = this.walk_expr(expr);
+                });
+            }
+            ExprKind::Binary(_, op, _) => self.node("binary", span, |this| {
+                this.atom(op.kind.to_str());
+                let _ = this.walk_expr(expr);
+            }),
+            ExprKind::Unary(op, _) => {
+                let label = if op.kind.is_postfix() { "postfix" } else { "unary" };
+                self.node(label, span, |this| {
+                    this.atom(op.kind.to_str());
+                    let _ = this.walk_expr(expr);
+                });
+            }
+            kind => {
+                let label = match kind {
+                    ExprKind::Array(_) => "array",
+                    ExprKind::Call(..) => "call",
+                    ExprKind::CallOptions(..) => "call-options",
+                    ExprKind::Delete(_) => "delete",
+                    ExprKind::Index(_, IndexKind::Index(_)) => "index",
+                    ExprKind::Index(_, IndexKind::Range(..)) => "slice",
+                    ExprKind::Member(..) => "member",
+                    ExprKind::New(_) => "new",
+                    ExprKind::Payable(_) => "payable",
+                    ExprKind::Ternary(..) => "ternary",
+                    ExprKind::Tuple(_) => "tuple",
+                    ExprKind::TypeCall(_) => "type-call",
+                    ExprKind::Type(_) => "type",
+                    ExprKind::Ident(_)
+                    | ExprKind::Lit(..)
+                    | ExprKind::Assign(..)
+                    | ExprKind::Binary(..)
+                    | ExprKind::Unary(..) => unreachable!(),
+                };
+                self.node(label, span, |this| {
+                    let _ = this.walk_expr(expr);
+                });
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_parameter_list(&mut self, list: &'ast ParameterList<'ast>) -> ControlFlow<Never> {
+        self.node("params", None, |this| {
+            let _ = this.walk_parameter_list(list);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_lit(&mut self, lit: &'ast Lit) -> ControlFlow<Never> {
+        // Literals are printed as valid Solidity, which is already a single atom.
+        let mut printer = print::Printer::new();
+        printer.print_lit(lit);
+        self.out.push(' ');
+        self.out.push_str(&printer.finish());
+        ControlFlow::Continue(())
+    }
+
+    fn visit_yul_stmt(&mut self, stmt: &'ast yul::Stmt<'ast>) -> ControlFlow<Never> {
+        let label = match &stmt.kind {
+            // These are already printed as a single node.
+            yul::StmtKind::Block(_) | yul::StmtKind::Expr(_) | yul::StmtKind::FunctionDef(_) => {
+                return self.walk_yul_stmt(stmt);
+            }
+            yul::StmtKind::AssignSingle(..) | yul::StmtKind::AssignMulti(..) => "assign",
+            yul::StmtKind::If(..) => "if",
+            yul::StmtKind::For { .. } => "for",
+            yul::StmtKind::Switch(_) => "switch",
+            yul::StmtKind::Leave => "leave",
+            yul::StmtKind::Break => "break",
+            yul::StmtKind::Continue => "continue",
+            yul::StmtKind::VarDecl(..) => "let",
+        };
+        self.node(label, Some(stmt.span), |this| {
+            let _ = this.walk_yul_stmt(stmt);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_yul_block(&mut self, block: &'ast yul::Block<'ast>) -> ControlFlow<Never> {
+        self.node("block", None, |this| {
+            let _ = this.walk_yul_block(block);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_yul_stmt_case(&mut self, case: &'ast yul::StmtSwitchCase<'ast>) -> ControlFlow<Never> {
+        self.node("case", None, |this| {
+            let _ = this.walk_yul_stmt_case(case);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_yul_function(&mut self, function: &'ast yul::Function<'ast>) -> ControlFlow<Never> {
+        self.node("function", None, |this| {
+            let _ = this.walk_yul_function(function);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_yul_expr_call(&mut self, call: &'ast yul::ExprCall<'ast>) -> ControlFlow<Never> {
+        self.node("call", None, |this| {
+            let _ = this.walk_yul_expr_call(call);
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn visit_doc_comments(&mut self, _doc_comments: &'ast DocComments<'ast>) -> ControlFlow<Never> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_path(&mut self, path: &'ast PathSlice) -> ControlFlow<Never> {
+        self.atom(&path.to_string());
+        ControlFlow::Continue(())
+    }
+
+    fn visit_ident(&mut self, ident: &'ast Ident) -> ControlFlow<Never> {
+        self.atom(ident.as_str());
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn atoms() {
+        let mut printer =
+            SExprPrinter { out: String::new(), depth: 0, spans: None, next_span: None };
+        for atom in ["a", "a.b", "uint256[]", "", "mapping(a => b)", "x\"y"] {
+            printer.atom(atom);
+        }
+        assert_eq!(printer.out, r#" a a.b uint256[] "" "mapping(a => b)" "x\"y""#);
+    }
+}
@@ -96,7 +96,7 @@ impl BinOpToken {
             Self::Star => BinOpKind::Mul,
             Self::Slash => BinOpKind::Div,
             Self::Percent => BinOpKind::Rem,
-            Self::Caret => BinOpKind::Pow,
+            Self::Caret => BinOpKind::BitXor,
             Self::And => BinOpKind::BitAnd,
             Self::Or => BinOpKind::BitOr,
             Self::Shl => BinOpKind::Shl,
@@ -375,7 +375,6 @@ impl TokenKind {
     #[inline]
     pub fn as_binop(&self) -> Option<BinOpKind> {
         match self {
-            Self::Eq => Some(BinOpKind::Eq),
             Self::Lt => Some(BinOpKind::Lt),
             Self::Le => Some(BinOpKind::Le),
             Self::EqEq => Some(BinOpKind::Eq),
@@ -463,6 +462,40 @@ impl TokenKind {
             }
         })
     }
+
+    /// Splits a compound token kind into its first character and the rest.
+    ///
+    /// This is the inverse of [`glue`](Self::glue).
+    pub const fn split(&self) -> Option<(Self, Self)> {
+        use BinOpToken::*;
+        use TokenKind::*;
+        Some(match *self {
+            EqEq => (Eq, Eq),
+            FatArrow => (Eq, Gt),
+            Le => (Lt, Eq),
+            Ge => (Gt, Eq),
+            Ne => (Not, Eq),
+            Walrus => (Colon, Eq),
+            AndAnd => (BinOp(And), BinOp(And)),
+            OrOr => (BinOp(Or), BinOp(Or)),
+            Arrow => (BinOp(Minus), Gt),
+            PlusPlus => (BinOp(Plus), BinOp(Plus)),
+            MinusMinus => (BinOp(Minus), BinOp(Minus)),
+            StarStar => (BinOp(Star), BinOp(Star)),
+            BinOp(Shl) => (Lt, Lt),
+            BinOp(Shr) => (Gt, Gt),
+            BinOp(Sar) => (Gt, BinOp(Shr)),
+            BinOpEq(Shl) => (Lt, Le),
+            BinOpEq(Shr) => (Gt, Ge),
+            BinOpEq(Sar) => (Gt, BinOpEq(Shr)),
+            BinOpEq(op) => (BinOp(op), Eq),
+
+            Eq | Lt | Gt | Not | Tilde | BinOp(_) | At | Dot | Comma | Semi | Colon | Question
+            | OpenDelim(_) | CloseDelim(_) | Literal(..) | Ident(_) | Comment(..) | Eof => {
+                return None
+            }
+        })
+    }
 }
 
 /// A single token.
@@ -741,6 +774,15 @@ impl Token {
     pub fn glue(&self, other: &Self) -> Option<Self> {
         self.kind.glue(&other.kind).map(|kind| Self::new(kind, self.span.to(other.span)))
     }
+
+    /// Splits a compound token into its first character and the rest.
+    ///
+    /// This is the inverse of [`glue`](Self::glue).
+    pub fn split(&self) -> Option<(Self, Self)> {
+        let (first, second) = self.kind.split()?;
+        let (lo, hi) = self.span.split_at(first.as_str().len() as u32);
+        Some((Self::new(first, lo), Self::new(second, hi)))
+    }
 }
 
 /// A description of a token.
@@ -787,3 +829,63 @@ impl TokenDescription {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solar_interface::BytePos;
+    use BinOpToken::*;
+    use TokenKind::*;
+
+    fn token(kind: TokenKind, lo: u32, hi: u32) -> Token {
+        Token::new(kind, Span::new(BytePos(lo), BytePos(hi)))
+    }
+
+    #[test]
+    fn glue() {
+        assert_eq!(Gt.glue(&Gt), Some(BinOp(Shr)));
+        assert_eq!(Gt.glue(&BinOp(Shr)), Some(BinOp(Sar)));
+        assert_eq!(BinOp(Shr).glue(&Ge), Some(BinOpEq(Sar)));
+        assert_eq!(BinOp(Star).glue(&BinOp(Star)), Some(StarStar));
+        assert_eq!(Lt.glue(&Le), Some(BinOpEq(Shl)));
+        assert_eq!(Gt.glue(&Lt), None);
+        assert_eq!(StarStar.glue(&Eq), None);
+
+        let shr = token(Gt, 0, 1).glue(&token(Gt, 1, 2)).unwrap();
+        assert_eq!(shr, token(BinOp(Shr), 0, 2));
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(BinOpEq(Shr).split(), Some((Gt, Ge)));
+        assert_eq!(BinOpEq(Sar).split(), Some((Gt, BinOpEq(Shr))));
+        assert_eq!(BinOpEq(Plus).split(), Some((BinOp(Plus), Eq)));
+        assert_eq!(StarStar.split(), Some((BinOp(Star), BinOp(Star))));
+        assert_eq!(Gt.split(), None);
+        assert_eq!(BinOp(Plus).split(), None);
+
+        let (first, rest) = token(BinOpEq(Shr), 4, 7).split().unwrap();
+        assert_eq!(first, token(Gt, 4, 5));
+        assert_eq!(rest, token(Ge, 5, 7));
+    }
+
+    #[test]
+    fn split_is_inverse_of_glue() {
+        let ops = [Plus, Minus, Star, Slash, Percent, Caret, And, Or, Shl, Shr, Sar];
+        let kinds = [EqEq, FatArrow, Le, Ge, Ne, Walrus, AndAnd, OrOr, Arrow, PlusPlus]
+            .into_iter()
+            .chain([MinusMinus, StarStar])
+            .chain(ops.into_iter().flat_map(|op| [BinOp(op), BinOpEq(op)]));
+        for kind in kinds {
+            let Some((first, second)) = kind.split() else {
+                assert!(matches!(
+                    kind,
+                    BinOp(Plus | Minus | Star | Slash | Percent | Caret | And | Or)
+                ));
+                continue;
+            };
+            assert_eq!(first.as_str().len(), 1, "{kind:?}");
+            assert_eq!(first.glue(&second), Some(kind.clone()), "{kind:?}");
+        }
+    }
+}
@@ -18,7 +18,7 @@ declare_visitors! {
         fn visit_source_unit(&mut self, source_unit: &#mut SourceUnit<'ast>) -> ControlFlow<Self::BreakValue> {
             // TODO: SAFETY: Idk
             let source_unit = unsafe { trustme::decouple_lt #_mut(source_unit) };
-            let SourceUnit { items } = source_unit;
+            let SourceUnit { items, licenses: _ } = source_unit;
             for item in items.iter #_mut() {
                 self.visit_item #_mut(item)?;
             }
@@ -569,8 +569,11 @@ declare_visitors! {
                 yul::ExprKind::Call(call) => {
                     self.visit_yul_expr_call #_mut(call)?;
                 }
-                yul::ExprKind::Lit(lit) => {
+                yul::ExprKind::Lit(lit, ty) => {
                     self.visit_lit #_mut(lit)?;
+                    if let Some(ty) = ty {
+                        self.visit_ident #_mut(ty)?;
+                    }
                 }
             }
             ControlFlow::Continue(())
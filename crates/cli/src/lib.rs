@@ -8,13 +8,15 @@
 use clap::Parser as _;
 use solar_config::{ErrorFormat, ImportMap};
 use solar_interface::{
-    diagnostics::{DiagCtxt, DynEmitter, HumanEmitter, JsonEmitter},
+    diagnostics::{DiagCtxt, DiagId, DynEmitter, HumanEmitter, JsonEmitter, Level, LintLevels},
     Result, Session, SourceMap,
 };
-use std::{path::Path, sync::Arc};
+use std::{io::Read, path::Path, sync::Arc};
 
 pub use solar_config::{self as config, version, Opts, UnstableOpts};
 
+pub mod standard_json;
+
 pub mod utils;
 
 #[cfg(all(unix, any(target_env = "gnu", target_os = "macos")))]
@@ -56,6 +58,14 @@ impl Compiler {
     pub fn run_default(&self) -> Result<()> {
         let Self { sess } = self;
 
+        if let Some(code) = &sess.opts.explain {
+            return self.explain(code);
+        }
+
+        if sess.opts.standard_json {
+            return self.standard_json();
+        }
+
         if sess.opts.language.is_yul() && !sess.opts.unstable.parse_yul {
             return Err(sess.dcx.err("Yul is not supported yet").emit());
         }
@@ -77,7 +87,7 @@ impl Compiler {
         for map in remappings {
             pcx.file_resolver.add_import_map(map.map, map.path);
         }
-        for path in &sess.opts.import_path {
+        for path in sess.opts.base_path.iter().chain(&sess.opts.import_path) {
             let new = pcx.file_resolver.add_import_path(path.clone());
             if !new {
                 let msg = format!("import path {} already specified", path.display());
@@ -95,6 +105,32 @@ impl Compiler {
         Ok(())
     }
 
+    /// Reads a standard JSON input from stdin, and prints the standard JSON output to stdout.
+    ///
+    /// See [`standard_json::run`].
+    pub fn standard_json(&self) -> Result<()> {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input).map_err(|e| {
+            self.sess.dcx.err(format!("failed to read standard JSON input: {e}")).emit()
+        })?;
+        println!("{}", standard_json::run(&self.sess, &input));
+        Ok(())
+    }
+
+    /// Prints the long-form explanation of the given error code.
+    pub fn explain(&self, code: &str) -> Result<()> {
+        let id = code.parse::<DiagId>().map_err(|e| self.sess.dcx.err(e).emit())?;
+        let Some(explanation) = id.explanation() else {
+            return Err(self
+                .sess
+                .dcx
+                .err(format!("no extended information for error code {id}"))
+                .emit());
+        };
+        print!("{explanation}");
+        Ok(())
+    }
+
     fn finish_diagnostics(&self) -> Result {
         self.sess.dcx.print_error_count()
     }
@@ -125,10 +161,22 @@ fn run_compiler_with(opts: Opts, f: impl FnOnce(&Compiler) -> Result + Send) ->
             Box::new(json)
         }
     };
-    let dcx = DiagCtxt::new(emitter).set_flags(|flags| {
+    let mut lint_levels = LintLevels::new();
+    let levels =
+        [(&opts.allow, Level::Allow), (&opts.warn, Level::Warning), (&opts.deny, Level::Error)];
+    for (lints, level) in levels {
+        for lint in lints {
+            if let Err(e) = lint_levels.set(lint, level) {
+                return Err(DiagCtxt::new_early().err(e).emit());
+            }
+        }
+    }
+    let dcx = DiagCtxt::new(emitter).set_lint_levels(lint_levels).set_flags(|flags| {
         flags.deduplicate_diagnostics &= !ui_testing;
         flags.track_diagnostics &= !ui_testing;
         flags.track_diagnostics |= opts.unstable.track_diagnostics;
+        flags.max_errors = opts.max_errors;
+        flags.can_emit_warnings = !opts.no_warnings;
     });
 
     let mut sess = Session::builder().dcx(dcx).source_map(source_map).opts(opts).build();
@@ -138,7 +186,10 @@ fn run_compiler_with(opts: Opts, f: impl FnOnce(&Compiler) -> Result + Send) ->
     let compiler = Compiler { sess };
     compiler.sess.enter_parallel(|| {
         let mut r = f(&compiler);
-        r = compiler.finish_diagnostics().and(r);
+        // Diagnostics are part of the output in standard JSON mode.
+        if !compiler.sess.opts.standard_json {
+            r = compiler.finish_diagnostics().and(r);
+        }
         r
     })
 }
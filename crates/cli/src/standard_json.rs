@@ -0,0 +1,159 @@
+//! solc standard JSON input and output.
+//!
+//! See <https://docs.soliditylang.org/en/latest/using-the-compiler.html#compiler-input-and-output-json-description>.
+
+use serde::{Deserialize, Serialize};
+use solar_config::ImportMap;
+use solar_interface::{diagnostics::JsonEmitter, source_map::FileName, Session};
+use solar_sema::{ast, thread_local::ThreadLocal, ParsingContext};
+use std::collections::BTreeMap;
+
+/// Standard JSON input. Unsupported fields are ignored.
+#[derive(Deserialize)]
+struct Input {
+    language: String,
+    sources: BTreeMap<String, InputSource>,
+    #[serde(default)]
+    settings: Settings,
+}
+
+#[derive(Deserialize)]
+struct InputSource {
+    content: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+struct Settings {
+    #[serde(default)]
+    remappings: Vec<String>,
+}
+
+/// Standard JSON output.
+#[derive(Serialize)]
+struct Output {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<serde_json::Value>,
+    sources: BTreeMap<String, OutputSource>,
+}
+
+#[derive(Serialize)]
+struct OutputSource {
+    id: u32,
+}
+
+/// Parses the sources of the given standard JSON input, and returns the standard JSON output.
+///
+/// All the diagnostics emitted in the meantime are reported in the `errors` field of the output
+/// instead of being passed to the session's emitter. They are still counted in the session's
+/// diagnostic context.
+pub fn run(sess: &Session, input: &str) -> String {
+    let (sources, diagnostics) = sess.dcx.capture(|| parse(sess, input));
+    let mut emitter = JsonEmitter::new(Box::new(std::io::sink()), sess.clone_source_map())
+        .ui_testing(sess.opts.unstable.ui_testing);
+    let errors = diagnostics.iter().map(|diag| emitter.solc_error(diag)).collect();
+    let output = Output { errors, sources };
+    if sess.opts.pretty_json {
+        serde_json::to_string_pretty(&output)
+    } else {
+        serde_json::to_string(&output)
+    }
+    .unwrap()
+}
+
+/// Loads and parses the sources, returning the parsed source unit names. Source IDs are assigned
+/// in the order of the names, like solc.
+fn parse(sess: &Session, input: &str) -> BTreeMap<String, OutputSource> {
+    let input = match serde_json::from_str::<Input>(input) {
+        Ok(input) => input,
+        Err(e) => {
+            sess.dcx.err(format!("failed to parse standard JSON input: {e}")).emit();
+            return BTreeMap::new();
+        }
+    };
+    if input.language != "Solidity" {
+        let msg = format!("unsupported standard JSON input language: {:?}", input.language);
+        sess.dcx.err(msg).note("only `Solidity` is supported").emit();
+        return BTreeMap::new();
+    }
+
+    let mut pcx = ParsingContext::new(sess);
+    for remapping in &input.settings.remappings {
+        match remapping.parse::<ImportMap>() {
+            Ok(map) => pcx.file_resolver.add_import_map(map.map, map.path),
+            Err(e) => {
+                sess.dcx.err(format!("invalid remapping {remapping:?}: {e}")).emit();
+            }
+        }
+    }
+    for (name, source) in input.sources {
+        let Some(content) = source.content else {
+            let msg = format!("source {name:?} has no `content`");
+            sess.dcx.err(msg).note("loading sources from `urls` is not supported").emit();
+            continue;
+        };
+        let filename = FileName::Real(name.into());
+        match sess.source_map().new_source_file(filename, || Ok(content)) {
+            Ok(file) => pcx.add_file(file),
+            Err(e) => {
+                sess.dcx.err(e.to_string()).emit();
+            }
+        }
+    }
+
+    let arenas = ThreadLocal::<ast::Arena>::new();
+    let sources = pcx.parse(&arenas);
+    let mut names = sources.iter().map(|s| s.file.name.display().to_string()).collect::<Vec<_>>();
+    names.sort();
+    names.into_iter().zip(0..).map(|(name, id)| (name, OutputSource { id })).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_test(input: &str) -> serde_json::Value {
+        let sess = Session::builder().with_test_emitter().build();
+        let output = sess.enter_parallel(|| run(&sess, input));
+        serde_json::from_str(&output).unwrap()
+    }
+
+    #[test]
+    fn inline_sources() {
+        let input = r#"{
+            "language": "Solidity",
+            "sources": {
+                "b.sol": { "content": "import \"lib/c.sol\"; contract B is C {}" },
+                "a.sol": { "content": "import \"./b.sol\"; contract A is B {}" },
+                "src/c.sol": { "content": "contract C {}" }
+            },
+            "settings": { "remappings": ["lib/=src/"], "optimizer": { "enabled": true } }
+        }"#;
+        let output = run_test(input);
+        assert_eq!(
+            output,
+            serde_json::json!({
+                "sources": { "a.sol": { "id": 0 }, "b.sol": { "id": 1 }, "src/c.sol": { "id": 2 } }
+            })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let input = r#"{
+            "language": "Solidity",
+            "sources": { "a.sol": { "content": "contract A {" }, "b.sol": { "urls": [] } }
+        }"#;
+        let output = run_test(input);
+        assert_eq!(output["sources"], serde_json::json!({ "a.sol": { "id": 0 } }));
+        let errors = output["errors"].as_array().unwrap();
+        assert_eq!(errors.len(), 2, "{errors:#?}");
+        assert_eq!(errors[0]["message"], r#"source "b.sol" has no `content`"#);
+        assert_eq!(errors[1]["severity"], "error");
+        assert_eq!(errors[1]["sourceLocation"]["file"], "a.sol");
+
+        let output = run_test("{");
+        let message = output["errors"][0]["message"].as_str().unwrap();
+        assert!(message.starts_with("failed to parse standard JSON input"), "{message}");
+        assert_eq!(output["sources"], serde_json::json!({}));
+    }
+}
@@ -116,6 +116,15 @@ str_enum! {
         // BinRuntime,
         /// Function signature hashes.
         Hashes,
+        /// `Debug` representation of the AST of each source file, emitted right after parsing.
+        AstDebug,
+        /// S-expression representation of the AST of each source file, emitted right after
+        /// parsing.
+        ParseTree,
+        /// Same as [`ParseTree`](Self::ParseTree), but with the byte range of each node.
+        ParseTreeSpans,
+        /// Lossless concrete syntax tree of each source file, including comments and whitespace.
+        Cst,
     }
 }
 
@@ -152,6 +161,35 @@ str_enum! {
     }
 }
 
+str_enum! {
+    /// Which tokens to print with `--dump-tokens`.
+    #[derive(Default)]
+    #[derive(EnumIs)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum DumpTokens {
+        /// Print all tokens except comments.
+        #[default]
+        Tokens,
+        /// Print all tokens, including comments and doc-comments.
+        WithTrivia,
+    }
+}
+
+str_enum! {
+    /// Information to print after parsing. See `--print`.
+    ///
+    /// This is for statistics and other information about the compilation itself; compiler
+    /// outputs, such as the parse tree, are selected with `--emit` instead.
+    #[derive(EnumIs)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum PrintKind {
+        /// Counts of each kind of AST node across all parsed files.
+        AstStats,
+        /// Symbol interner statistics after parsing.
+        SymbolStats,
+    }
+}
+
 str_enum! {
     /// How errors and other messages are produced.
     #[derive(Default)]
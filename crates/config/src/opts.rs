@@ -1,7 +1,8 @@
 //! Solar CLI arguments.
 
 use crate::{
-    CompilerOutput, CompilerStage, Dump, ErrorFormat, EvmVersion, ImportMap, Language, Threads,
+    CompilerOutput, CompilerStage, Dump, DumpTokens, ErrorFormat, EvmVersion, ImportMap, Language,
+    PrintKind, Threads,
 };
 use std::{num::NonZeroUsize, path::PathBuf};
 
@@ -22,8 +23,15 @@ pub struct Opts {
     /// Files to compile or import remappings.
     #[cfg_attr(feature = "clap", arg(value_hint = ValueHint::FilePath))]
     pub input: Vec<PathBuf>,
-    /// Directory to search for files.
-    #[cfg_attr(feature = "clap", arg(help_heading = "Input options", long, short = 'I', visible_alias = "base-path", value_hint = ValueHint::FilePath))]
+    /// Base directory to search for files. It is searched before any import paths.
+    #[cfg_attr(
+        feature = "clap",
+        arg(help_heading = "Input options", long, value_hint = ValueHint::DirPath)
+    )]
+    pub base_path: Option<PathBuf>,
+    /// Directory to search for files. Can be specified multiple times; directories are searched
+    /// in order, after the base path.
+    #[cfg_attr(feature = "clap", arg(help_heading = "Input options", long, short = 'I', visible_alias = "include-path", value_hint = ValueHint::FilePath))]
     pub import_path: Vec<PathBuf>,
     /// Map to search for files. Can also be provided as a positional argument.
     #[cfg_attr(
@@ -31,6 +39,15 @@ pub struct Opts {
         arg(help_heading = "Input options", long, short = 'm', value_name = "MAP=PATH")
     )]
     pub import_map: Vec<ImportMap>,
+    /// Emit a warning instead of an error for input files that cannot be read, and skip them.
+    #[cfg_attr(feature = "clap", arg(help_heading = "Input options", long))]
+    pub ignore_missing_files: bool,
+    /// Read a solc standard JSON input from stdin, and print a standard JSON output to stdout.
+    ///
+    /// Only inline source `content` and `settings.remappings` are supported. Sources are parsed,
+    /// and diagnostics are reported in the `errors` field of the output.
+    #[cfg_attr(feature = "clap", arg(help_heading = "Input options", long))]
+    pub standard_json: bool,
     /// Source code language. Only Solidity is currently implemented.
     #[cfg_attr(
         feature = "clap",
@@ -44,9 +61,57 @@ pub struct Opts {
     /// EVM version.
     #[cfg_attr(feature = "clap", arg(long, value_enum, default_value_t))]
     pub evm_version: EvmVersion,
+    /// Solidity language version, used to select which keywords are reserved.
+    ///
+    /// Words that only became keywords in later versions, such as `constructor` or `virtual`, can
+    /// be used as identifiers. Defaults to the latest version.
+    #[cfg_attr(feature = "clap", arg(long, value_name = "VERSION"))]
+    pub language_version: Option<semver::Version>,
+    /// Maximum nesting depth of expressions, statements, and types when parsing.
+    ///
+    /// Defaults to 256.
+    #[cfg_attr(feature = "clap", arg(long, value_name = "DEPTH"))]
+    pub max_parse_depth: Option<usize>,
+    /// Maximum size of an input or imported file, in bytes.
+    ///
+    /// Defaults to 16 MiB.
+    #[cfg_attr(feature = "clap", arg(long, value_name = "BYTES"))]
+    pub max_file_size: Option<u64>,
     /// Stop execution after the given compiler stage.
     #[cfg_attr(feature = "clap", arg(long, value_enum))]
     pub stop_after: Option<CompilerStage>,
+    /// Only parse the input files, without running any semantic checks.
+    ///
+    /// Equivalent to `--stop-after parsing`. Only errors emitted while loading and parsing files
+    /// result in a non-zero exit code.
+    #[cfg_attr(feature = "clap", arg(long, conflicts_with = "stop_after"))]
+    pub parse_only: bool,
+    /// Print the tokens of each input file, one per line, and stop before parsing.
+    ///
+    /// Each token is printed with its kind and byte range. Comments are only included with
+    /// `--dump-tokens=with-trivia`.
+    #[cfg_attr(
+        feature = "clap",
+        arg(
+            long,
+            value_enum,
+            value_name = "MODE",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "tokens"
+        )
+    )]
+    pub dump_tokens: Option<DumpTokens>,
+    /// Comma separated list of information to print to stdout after parsing.
+    ///
+    /// `ast-stats` prints a table of the number of AST nodes of each kind, summed over all parsed
+    /// files. `symbol-stats` prints the number of interned symbols and the interner's memory
+    /// usage.
+    #[cfg_attr(feature = "clap", arg(long, value_name = "INFO", value_delimiter = ','))]
+    pub print: Vec<PrintKind>,
+    /// Print the time spent lexing and parsing each file, and in total, to stderr.
+    #[cfg_attr(feature = "clap", arg(long))]
+    pub time_passes: bool,
 
     /// Directory to write output files.
     #[cfg_attr(feature = "clap", arg(long, value_hint = ValueHint::DirPath))]
@@ -55,7 +120,14 @@ pub struct Opts {
     #[cfg_attr(feature = "clap", arg(long, value_delimiter = ','))]
     pub emit: Vec<CompilerOutput>,
 
+    /// Print a detailed explanation of the given error code and exit.
+    #[cfg_attr(feature = "clap", arg(long, value_name = "CODE"))]
+    pub explain: Option<String>,
+
     /// Coloring.
+    ///
+    /// `auto` only uses colors when writing to a terminal, and respects the `NO_COLOR` and
+    /// `CLICOLOR_FORCE` environment variables.
     #[cfg(feature = "clap")] // TODO
     #[cfg_attr(
         feature = "clap",
@@ -65,10 +137,13 @@ pub struct Opts {
     /// Use verbose output.
     #[cfg_attr(feature = "clap", arg(help_heading = "Display options", long, short))]
     pub verbose: bool,
-    /// Pretty-print JSON output.
+    /// Pretty-print JSON and AST output.
     ///
     /// Does not include errors. See `--pretty-json-err`.
-    #[cfg_attr(feature = "clap", arg(help_heading = "Display options", long))]
+    #[cfg_attr(
+        feature = "clap",
+        arg(help_heading = "Display options", long, visible_alias = "pretty")
+    )]
     pub pretty_json: bool,
     /// Pretty-print error JSON output.
     #[cfg_attr(feature = "clap", arg(help_heading = "Display options", long))]
@@ -79,6 +154,42 @@ pub struct Opts {
         arg(help_heading = "Display options", long, value_enum, default_value_t)
     )]
     pub error_format: ErrorFormat,
+    /// Stop emitting errors after the given number of errors have been emitted.
+    ///
+    /// Further errors are suppressed, but still counted in the final summary.
+    #[cfg_attr(feature = "clap", arg(help_heading = "Display options", long, value_name = "N"))]
+    pub max_errors: Option<NonZeroUsize>,
+    /// Do not emit warnings.
+    ///
+    /// Warnings are suppressed, but still counted in the final summary. Warnings that are
+    /// promoted to errors with `--deny` are still emitted.
+    #[cfg_attr(feature = "clap", arg(help_heading = "Display options", long))]
+    pub no_warnings: bool,
+
+    /// Allow the given warnings. Can be a diagnostic ID, or `warnings` for all warnings.
+    #[cfg_attr(
+        feature = "clap",
+        arg(help_heading = "Lint options", long, short = 'A', value_name = "LINT")
+    )]
+    pub allow: Vec<String>,
+    /// Emit the given diagnostics as warnings. Can be a diagnostic ID, or `warnings`.
+    #[cfg_attr(
+        feature = "clap",
+        arg(help_heading = "Lint options", long, short = 'W', value_name = "LINT")
+    )]
+    pub warn: Vec<String>,
+    /// Emit the given warnings as errors. Can be a diagnostic ID, or `warnings` for all warnings.
+    ///
+    /// `--deny` takes precedence over `--warn`, which takes precedence over `--allow`.
+    #[cfg_attr(
+        feature = "clap",
+        arg(help_heading = "Lint options", long, short = 'D', value_name = "LINT")
+    )]
+    pub deny: Vec<String>,
+    /// Warn about `solidity` version pragmas that cannot be satisfied by any single compiler
+    /// version, such as `^0.7.0` and `^0.8.0`.
+    #[cfg_attr(feature = "clap", arg(help_heading = "Lint options", long))]
+    pub warn_conflicting_pragmas: bool,
 
     /// Unstable flags. WARNING: these are completely unstable, and may change at any time.
     ///
@@ -1,19 +1,29 @@
 use super::{
-    emitter::HumanEmitter, BugAbort, Diag, DiagBuilder, DiagMsg, DynEmitter, EmissionGuarantee,
-    EmittedDiagnostics, ErrorGuaranteed, FatalAbort, HumanBufferEmitter, Level, SilentEmitter,
+    emitter::HumanEmitter, BugAbort, Diag, DiagBuilder, DiagId, DiagMsg, DynEmitter,
+    EmissionGuarantee, EmittedDiagnostics, ErrorGuaranteed, FatalAbort, HumanBufferEmitter, Level,
+    LocalEmitter, SilentEmitter,
 };
 use crate::{Result, SourceMap};
 use anstream::ColorChoice;
-use solar_data_structures::{map::FxHashSet, sync::Lock};
+use solar_data_structures::{
+    map::{FxHashMap, FxHashSet},
+    sync::Lock,
+};
 use std::{borrow::Cow, hash::BuildHasher, num::NonZeroUsize, sync::Arc};
 
 /// Flags that control the behaviour of a [`DiagCtxt`].
 #[derive(Clone, Copy)]
 pub struct DiagCtxtFlags {
     /// If false, warning-level lints are suppressed.
+    ///
+    /// Suppressed warnings are still counted towards the warning count.
     pub can_emit_warnings: bool,
     /// If Some, the Nth error-level diagnostic is upgraded to bug-level.
     pub treat_err_as_bug: Option<NonZeroUsize>,
+    /// If Some, error-level diagnostics emitted after the Nth one are suppressed.
+    ///
+    /// Suppressed errors are still counted towards the error count.
+    pub max_errors: Option<NonZeroUsize>,
     /// If true, identical diagnostics are reported only once.
     pub deduplicate_diagnostics: bool,
     /// Track where errors are created. Enabled with `-Ztrack-diagnostics`, and by default in debug
@@ -26,12 +36,63 @@ impl Default for DiagCtxtFlags {
         Self {
             can_emit_warnings: true,
             treat_err_as_bug: None,
+            max_errors: None,
             deduplicate_diagnostics: true,
             track_diagnostics: cfg!(debug_assertions),
         }
     }
 }
 
+/// Levels of warnings, overridden with the `-A`, `-W`, and `-D` command line flags.
+///
+/// Warnings are selected either by their [`DiagId`], or all at once with `warnings`.
+/// Levels of specific IDs take precedence over the level of `warnings`.
+#[derive(Clone, Debug, Default)]
+pub struct LintLevels {
+    warnings: Option<Level>,
+    ids: FxHashMap<DiagId, Level>,
+}
+
+impl LintLevels {
+    /// Creates a new, empty set of lint levels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the level of `lint`, which is either `warnings` or a diagnostic ID.
+    ///
+    /// `level` must be one of [`Level::Allow`], [`Level::Warning`], or [`Level::Error`].
+    pub fn set(&mut self, lint: &str, level: Level) -> Result<(), String> {
+        assert!(
+            matches!(level, Level::Allow | Level::Warning | Level::Error),
+            "invalid lint level: {level:?}"
+        );
+        if lint == "warnings" {
+            self.warnings = Some(level);
+        } else {
+            let id = lint.parse().map_err(|_| {
+                format!("unknown lint `{lint}`; expected `warnings` or a 4-digit diagnostic ID")
+            })?;
+            self.ids.insert(id, level);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if no levels are overridden.
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_none() && self.ids.is_empty()
+    }
+
+    /// Returns the overridden level of a warning with the given ID, and the name of the lint
+    /// that set it.
+    fn get(&self, id: Option<&DiagId>) -> Option<(Level, String)> {
+        if let Some((id, &level)) = id.and_then(|id| self.ids.get_key_value(id)) {
+            return Some((level, id.to_string()));
+        }
+        self.warnings.map(|level| (level, "warnings".to_string()))
+    }
+}
+
 /// A handler deals with errors and other compiler output.
 /// Certain errors (fatal, bug, unimpl) may cause immediate exit,
 /// others log errors for later reporting.
@@ -44,6 +105,8 @@ struct DiagCtxtInner {
 
     flags: DiagCtxtFlags,
 
+    lint_levels: LintLevels,
+
     /// The number of errors that have been emitted, including duplicates.
     ///
     /// This is not necessarily the count that's reported to the user once
@@ -53,6 +116,10 @@ struct DiagCtxtInner {
     warn_count: usize,
     /// The warning count, used for a recap upon finishing
     deduplicated_warn_count: usize,
+    /// The number of errors that were not emitted because of `max_errors`.
+    suppressed_err_count: usize,
+    /// The number of warnings that were not emitted because of `can_emit_warnings`.
+    suppressed_warn_count: usize,
 
     /// This set contains a hash of every diagnostic that has been emitted by this `DiagCtxt`.
     /// These hashes are used to avoid emitting the same error twice.
@@ -66,10 +133,13 @@ impl DiagCtxt {
             inner: Lock::new(DiagCtxtInner {
                 emitter,
                 flags: DiagCtxtFlags::default(),
+                lint_levels: LintLevels::default(),
                 err_count: 0,
                 deduplicated_err_count: 0,
                 warn_count: 0,
                 deduplicated_warn_count: 0,
+                suppressed_err_count: 0,
+                suppressed_warn_count: 0,
                 emitted_diagnostics: FxHashSet::default(),
             }),
         }
@@ -123,6 +193,30 @@ impl DiagCtxt {
         });
     }
 
+    /// Runs the given closure, collecting all the diagnostics emitted in the meantime instead of
+    /// passing them to the emitter.
+    ///
+    /// Diagnostics are still counted, and lint levels still apply.
+    pub fn capture<R>(&self, f: impl FnOnce() -> R) -> (R, Vec<Diag>) {
+        let prev = std::mem::replace(&mut self.inner.lock().emitter, Box::new(LocalEmitter::new()));
+        let r = f();
+        let mut local = std::mem::replace(&mut self.inner.lock().emitter, prev);
+        let diagnostics =
+            local.downcast_mut::<LocalEmitter>().map(std::mem::take).unwrap_or_default();
+        (r, diagnostics.into_diagnostics())
+    }
+
+    /// Starts buffering diagnostics instead of passing them to the emitter.
+    ///
+    /// Diagnostics are still counted, and lint levels still apply. The buffered diagnostics are
+    /// passed to the previous emitter once the returned handle is flushed or dropped.
+    ///
+    /// See [`BufferedDiagCtxt`] for more details.
+    pub fn buffered(&self) -> BufferedDiagCtxt<'_> {
+        let prev = std::mem::replace(&mut self.inner.lock().emitter, Box::new(LocalEmitter::new()));
+        BufferedDiagCtxt { dcx: self, prev: Some(prev) }
+    }
+
     fn wrap_emitter(&self, f: impl FnOnce(Box<DynEmitter>) -> Box<DynEmitter>) {
         struct FakeEmitter;
         impl crate::diagnostics::Emitter for FakeEmitter {
@@ -150,6 +244,12 @@ impl DiagCtxt {
         self
     }
 
+    /// Sets the levels of warnings.
+    pub fn set_lint_levels(mut self, lint_levels: LintLevels) -> Self {
+        self.inner.get_mut().lint_levels = lint_levels;
+        self
+    }
+
     /// Disables emitting warnings.
     pub fn disable_warnings(self) -> Self {
         self.set_flags(|f| f.can_emit_warnings = false)
@@ -182,6 +282,12 @@ impl DiagCtxt {
         self.inner.lock().err_count
     }
 
+    /// Returns the number of warnings that have been emitted, including duplicates and warnings
+    /// that were suppressed.
+    pub fn warn_count(&self) -> usize {
+        self.inner.lock().warn_count
+    }
+
     /// Returns `Err` if any errors have been emitted.
     pub fn has_errors(&self) -> Result<(), ErrorGuaranteed> {
         if self.inner.lock().has_errors() {
@@ -269,16 +375,82 @@ impl DiagCtxt {
     }
 }
 
-impl DiagCtxtInner {
-    fn emit_diagnostic(&mut self, mut diagnostic: Diag) -> Result<(), ErrorGuaranteed> {
-        self.emit_diagnostic_without_consuming(&mut diagnostic)
+/// A handle to a [`DiagCtxt`] that is buffering diagnostics.
+///
+/// Created with [`DiagCtxt::buffered`]. This allows work to be done in any order, such as in
+/// parallel, while still emitting diagnostics in a deterministic order.
+///
+/// Dropping the handle without flushing it emits the buffered diagnostics in emission order.
+#[must_use = "buffered diagnostics are only emitted when flushed or dropped"]
+pub struct BufferedDiagCtxt<'a> {
+    dcx: &'a DiagCtxt,
+    prev: Option<Box<DynEmitter>>,
+}
+
+impl BufferedDiagCtxt<'_> {
+    /// Emits the buffered diagnostics in emission order.
+    pub fn flush(mut self) {
+        self.flush_with(|_| {});
     }
 
+    /// Emits the buffered diagnostics sorted by their primary span.
+    ///
+    /// Since source files are laid out sequentially in the source map, this orders diagnostics by
+    /// file and then by position in the file. Diagnostics without a primary span are emitted last.
+    /// The sort is stable, so diagnostics with the same span are emitted in emission order.
+    pub fn flush_sorted(mut self) {
+        self.flush_with(|diags| {
+            diags.sort_by_key(|diag| {
+                let span = diag.span.primary_span();
+                (span.is_none(), span.map(|span| (span.lo(), span.hi())))
+            })
+        });
+    }
+
+    fn flush_with(&mut self, f: impl FnOnce(&mut Vec<Diag>)) {
+        let Some(prev) = self.prev.take() else { return };
+        let mut inner = self.dcx.inner.lock();
+        let mut local = std::mem::replace(&mut inner.emitter, prev);
+        let mut diagnostics = local
+            .downcast_mut::<LocalEmitter>()
+            .map(std::mem::take)
+            .unwrap_or_default()
+            .into_diagnostics();
+        f(&mut diagnostics);
+        // The diagnostics have already been counted and deduplicated when they were buffered.
+        for diagnostic in &diagnostics {
+            inner.emitter.emit_diagnostic(diagnostic);
+        }
+    }
+}
+
+impl Drop for BufferedDiagCtxt<'_> {
+    fn drop(&mut self) {
+        self.flush_with(|_| {});
+    }
+}
+
+impl DiagCtxtInner {
     fn emit_diagnostic_without_consuming(
         &mut self,
         diagnostic: &mut Diag,
     ) -> Result<(), ErrorGuaranteed> {
+        if diagnostic.level == Level::Warning {
+            if let Some((level, lint)) = self.lint_levels.get(diagnostic.code.as_ref()) {
+                if level == Level::Error {
+                    let note = format!("requested on the command line with `-D {lint}`");
+                    diagnostic.note_once(note);
+                }
+                diagnostic.level = level;
+            }
+        }
+
         if diagnostic.level == Level::Warning && !self.flags.can_emit_warnings {
+            let already_emitted = self.insert_diagnostic(diagnostic);
+            if !(self.flags.deduplicate_diagnostics && already_emitted) {
+                self.suppressed_warn_count += 1;
+            }
+            self.bump_warn_count();
             return Ok(());
         }
 
@@ -290,6 +462,15 @@ impl DiagCtxtInner {
             diagnostic.level = Level::Bug;
         }
 
+        if diagnostic.level == Level::Error && self.too_many_errors() {
+            let already_emitted = self.insert_diagnostic(diagnostic);
+            if !(self.flags.deduplicate_diagnostics && already_emitted) {
+                self.suppressed_err_count += 1;
+            }
+            self.bump_err_count();
+            return Err(ErrorGuaranteed::new_unchecked());
+        }
+
         let already_emitted = self.insert_diagnostic(diagnostic);
         if !(self.flags.deduplicate_diagnostics && already_emitted) {
             // Remove duplicate `Once*` subdiagnostics.
@@ -331,29 +512,45 @@ impl DiagCtxtInner {
             return Ok(());
         }
 
-        let warnings = |count| match count {
-            0 => unreachable!(),
-            1 => Cow::from("1 warning emitted"),
-            count => Cow::from(format!("{count} warnings emitted")),
+        let suppressed_warnings = self.suppressed_warn_count;
+        let warnings = |count| {
+            let emitted = count - suppressed_warnings;
+            let plural = |count| if count == 1 { "" } else { "s" };
+            match (emitted, suppressed_warnings) {
+                (0, 0) => unreachable!(),
+                (e, 0) => Cow::from(format!("{e} warning{} emitted", plural(e))),
+                (0, s) => Cow::from(format!("{s} warning{} suppressed", plural(s))),
+                (e, s) => Cow::from(format!("{e} warning{} emitted, {s} suppressed", plural(e),)),
+            }
         };
-        let errors = |count| match count {
-            0 => unreachable!(),
-            1 => Cow::from("aborting due to 1 previous error"),
-            count => Cow::from(format!("aborting due to {count} previous errors")),
+        let suppressed_errors = self.suppressed_err_count;
+        let errors = |count| {
+            let msg = match count {
+                0 => unreachable!(),
+                1 => Cow::from("aborting due to 1 previous error"),
+                count => Cow::from(format!("aborting due to {count} previous errors")),
+            };
+            match suppressed_errors {
+                0 => msg,
+                _ => Cow::from(format!("{msg}; further errors suppressed")),
+            }
         };
 
-        match (self.deduplicated_err_count, self.deduplicated_warn_count) {
-            (0, 0) => Ok(()),
+        let warn_count = self.deduplicated_warn_count + self.suppressed_warn_count;
+        let err_count = self.deduplicated_err_count + self.suppressed_err_count;
+        let msg = match (err_count, warn_count) {
+            (0, 0) => return Ok(()),
             (0, w) => {
                 self.emitter.emit_diagnostic(&Diag::new(Level::Warning, warnings(w)));
-                Ok(())
+                return Ok(());
             }
-            (e, 0) => self.emit_diagnostic(Diag::new(Level::Error, errors(e))),
-            (e, w) => self.emit_diagnostic(Diag::new(
-                Level::Error,
-                format!("{}; {}", errors(e), warnings(w)),
-            )),
-        }
+            (e, 0) => errors(e),
+            (e, w) => Cow::from(format!("{}; {}", errors(e), warnings(w))),
+        };
+        // Emit directly to bypass `max_errors`.
+        self.emitter.emit_diagnostic(&Diag::new(Level::Error, msg));
+        self.bump_err_count();
+        Err(ErrorGuaranteed::new_unchecked())
     }
 
     /// Inserts the given diagnostic into the set of emitted diagnostics.
@@ -367,6 +564,10 @@ impl DiagCtxtInner {
         self.flags.treat_err_as_bug.is_some_and(|c| self.err_count >= c.get())
     }
 
+    fn too_many_errors(&self) -> bool {
+        self.flags.max_errors.is_some_and(|max| self.deduplicated_err_count >= max.get())
+    }
+
     fn bump_err_count(&mut self) {
         self.err_count += 1;
         self.panic_if_treat_err_as_bug();
@@ -391,3 +592,93 @@ impl DiagCtxtInner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{source_map::FileName, BytePos, Span};
+
+    #[test]
+    fn buffered() {
+        let sm = Arc::<SourceMap>::default();
+        let dcx = DiagCtxt::with_buffer_emitter(Some(sm.clone()), ColorChoice::Never);
+        let a = sm.new_source_file(FileName::Custom("a".into()), || Ok("aaaa".into())).unwrap();
+        let b = sm.new_source_file(FileName::Custom("b".into()), || Ok("bbbb".into())).unwrap();
+        let span = |file: &crate::source_map::SourceFile, lo: u32| {
+            Span::new(file.start_pos + BytePos(lo), file.start_pos + BytePos(lo + 1))
+        };
+
+        let buffered = dcx.buffered();
+        dcx.warn("b2").span(span(&b, 2)).emit();
+        dcx.warn("no span").emit();
+        dcx.warn("a3").span(span(&a, 3)).emit();
+        dcx.warn("b0").span(span(&b, 0)).emit();
+        dcx.warn("a0").span(span(&a, 0)).emit();
+        assert_eq!(dcx.warn_count(), 5);
+        buffered.flush_sorted();
+
+        let out = dcx.emitted_diagnostics().unwrap().to_string();
+        let order = ["a0", "a3", "b0", "b2", "no span"].map(|msg| {
+            out.find(&format!("warning: {msg}\n")).unwrap_or_else(|| panic!("{msg}: {out}"))
+        });
+        assert!(order.is_sorted(), "{out}");
+        assert_eq!(dcx.warn_count(), 5);
+
+        // Dropping flushes in emission order.
+        let buffered = dcx.buffered();
+        dcx.warn("second").emit();
+        dcx.warn("first").span(span(&a, 0)).emit();
+        drop(buffered);
+        let out = dcx.emitted_diagnostics().unwrap().to_string();
+        assert!(out.find("warning: second").unwrap() < out.find("warning: first").unwrap());
+    }
+
+    #[test]
+    fn labels_and_notes() {
+        let sm = Arc::<SourceMap>::default();
+        let dcx = DiagCtxt::with_buffer_emitter(Some(sm.clone()), ColorChoice::Never);
+        let src = "uint256 x;\nuint256 x;\n";
+        let file = sm.new_source_file(FileName::Custom("a".into()), || Ok(src.into())).unwrap();
+        let span = |lo: u32, hi: u32| {
+            Span::new(file.start_pos + BytePos(lo), file.start_pos + BytePos(hi))
+        };
+
+        dcx.err("duplicate definition")
+            .span(span(19, 20))
+            .span_label(span(19, 20), "defined again here")
+            .span_label(span(8, 9), "previously defined here")
+            .note("names must be unique")
+            .note("second note")
+            .emit();
+
+        let out = dcx.emitted_diagnostics().unwrap().to_string();
+        let order = [
+            "error: duplicate definition",
+            "previously defined here",
+            "defined again here",
+            "= note: names must be unique",
+            "= note: second note",
+        ]
+        .map(|s| out.find(s).unwrap_or_else(|| panic!("{s}: {out}")));
+        assert!(order.is_sorted(), "{out}");
+    }
+
+    #[test]
+    fn max_errors_deduplicates_suppressed() {
+        let dcx = DiagCtxt::with_buffer_emitter(None, ColorChoice::Never).set_flags(|flags| {
+            flags.max_errors = NonZeroUsize::new(1);
+        });
+        for _ in 0..3 {
+            dcx.err("first").emit();
+            dcx.err("second").emit();
+        }
+        dcx.print_error_count().unwrap_err();
+
+        let out = dcx.emitted_diagnostics().unwrap().to_string();
+        assert!(!out.contains("error: second"), "{out}");
+        assert!(
+            out.contains("error: aborting due to 2 previous errors; further errors suppressed"),
+            "{out}"
+        );
+    }
+}
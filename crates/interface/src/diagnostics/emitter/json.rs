@@ -65,6 +65,12 @@ impl JsonEmitter {
         self
     }
 
+    /// Converts the given diagnostic to a JSON error object, as found in the `errors` field of
+    /// solc's standard JSON output.
+    pub fn solc_error(&mut self, diagnostic: &crate::diagnostics::Diag) -> serde_json::Value {
+        serde_json::to_value(self.solc_diagnostic(diagnostic)).unwrap()
+    }
+
     fn source_map(&self) -> &Arc<SourceMap> {
         Emitter::source_map(self).unwrap()
     }
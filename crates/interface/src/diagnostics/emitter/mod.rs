@@ -46,6 +46,15 @@ impl DynEmitter {
             None
         }
     }
+
+    // TODO: Remove when dyn trait upcasting is stable.
+    pub(crate) fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        if (*self).type_id() == std::any::TypeId::of::<T>() {
+            unsafe { Some(&mut *(self as *mut dyn Emitter as *mut T)) }
+        } else {
+            None
+        }
+    }
 }
 
 /// Diagnostic emitter.
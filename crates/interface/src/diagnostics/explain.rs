@@ -0,0 +1,100 @@
+//! Long-form explanations of diagnostic IDs.
+//!
+//! Diagnostic IDs are shared with `solc` where possible.
+
+/// Returns the explanation for the given diagnostic ID.
+pub(super) fn explanation(id: u32) -> Option<&'static str> {
+    EXPLANATIONS.binary_search_by_key(&id, |&(id, _)| id).ok().map(|i| EXPLANATIONS[i].1)
+}
+
+/// Sorted by ID.
+static EXPLANATIONS: &[(u32, &str)] = &[
+    (
+        2314,
+        "\
+The parser expected a specific token, but found something else.
+
+This is usually caused by a missing semicolon, an unbalanced delimiter, or a
+keyword used where an identifier is expected.
+
+Erroneous code example:
+
+    contract C {
+        uint256 x = 1
+    }
+
+The state variable declaration must end with a semicolon:
+
+    contract C {
+        uint256 x = 1;
+    }
+",
+    ),
+    (
+        3445,
+        "\
+A free or contract function is named `receive` or `fallback`.
+
+These names are reserved for the special receive and fallback functions, which
+are declared without the `function` keyword.
+
+Erroneous code example:
+
+    contract C {
+        function receive() external payable {}
+    }
+
+If this is meant to be the contract's receive function, remove the `function`
+keyword:
+
+    contract C {
+        receive() external payable {}
+    }
+",
+    ),
+    (
+        6162,
+        "\
+A parameter of a function type has a name.
+
+Naming function type parameters is deprecated, and the names are ignored.
+
+Erroneous code example:
+
+    function(uint256 amount) external f;
+
+Remove the parameter name:
+
+    function(uint256) external f;
+",
+    ),
+    (
+        9592,
+        "\
+A Yul `switch` statement has only a `default` case.
+
+The `switch` is redundant, as the default case is always taken.
+
+Erroneous code example:
+
+    switch x
+    default { y := 1 }
+
+Use the body of the default case directly:
+
+    y := 1
+",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted() {
+        assert!(EXPLANATIONS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(explanation(2314).is_some());
+        assert!(explanation(1).is_none());
+    }
+}
@@ -10,7 +10,7 @@ mod builder;
 pub use builder::{DiagBuilder, EmissionGuarantee};
 
 mod context;
-pub use context::{DiagCtxt, DiagCtxtFlags};
+pub use context::{BufferedDiagCtxt, DiagCtxt, DiagCtxtFlags, LintLevels};
 
 mod emitter;
 #[cfg(feature = "json")]
@@ -19,6 +19,8 @@ pub use emitter::{
     DynEmitter, Emitter, HumanBufferEmitter, HumanEmitter, LocalEmitter, SilentEmitter,
 };
 
+mod explain;
+
 mod message;
 pub use message::{DiagMsg, MultiSpan, SpanLabel};
 
@@ -111,6 +113,34 @@ impl DiagId {
     pub fn as_string(&self) -> String {
         format!("{:04}", self.id)
     }
+
+    /// Returns the long-form explanation of this diagnostic ID, if any.
+    ///
+    /// This is what is printed by `solar --explain <CODE>`.
+    pub fn explanation(&self) -> Option<&'static str> {
+        explain::explanation(self.id)
+    }
+}
+
+impl fmt::Display for DiagId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.id)
+    }
+}
+
+impl std::str::FromStr for DiagId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid error code `{s}`; expected 4 decimal digits");
+        if s.len() != 4 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+        match s.parse() {
+            Ok(id @ 1..=9999) => Ok(Self { id }),
+            _ => Err(err()),
+        }
+    }
 }
 
 /// Used for creating an error code. The input must be exactly 4 decimal digits.
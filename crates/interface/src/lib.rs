@@ -19,7 +19,7 @@ mod pos;
 pub use pos::{BytePos, CharPos, RelativeBytePos};
 
 mod session;
-pub use session::{Session, SessionBuilder};
+pub use session::{Session, SessionBuilder, SourceCache};
 
 pub mod source_map;
 pub use source_map::SourceMap;
@@ -28,7 +28,7 @@ mod span;
 pub use span::Span;
 
 mod symbol;
-pub use symbol::{kw, sym, Ident, Symbol};
+pub use symbol::{kw, sym, Ident, InternerStats, Symbol};
 
 pub mod panic_hook;
 
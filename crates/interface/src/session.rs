@@ -1,9 +1,13 @@
 use crate::{
     diagnostics::{DiagCtxt, EmittedDiagnostics},
+    source_map::FileName,
     ColorChoice, SessionGlobals, SourceMap,
 };
 use solar_config::{CompilerOutput, CompilerStage, Opts, UnstableOpts};
-use std::sync::Arc;
+use std::{
+    any::Any,
+    sync::{Arc, OnceLock},
+};
 
 /// Information about the current compiler session.
 #[derive(derive_builder::Builder)]
@@ -18,6 +22,21 @@ pub struct Session {
     /// The compiler options.
     #[builder(default)]
     pub opts: Opts,
+
+    /// The token cache, created by the parser on first use. See [`Session::token_cache`].
+    #[builder(setter(skip))]
+    token_cache: OnceLock<Box<dyn SourceCache>>,
+}
+
+/// A cache of data derived from source files, stored in a [`Session`].
+///
+/// This is implemented by the parser's token cache, as tokens are not defined in this crate.
+pub trait SourceCache: Any + Send + Sync {
+    /// Removes the cached data of the given file, returning `true` if there was an entry.
+    fn invalidate(&self, name: &FileName) -> bool;
+
+    /// Returns `self` as [`Any`], to downcast it to the concrete cache type.
+    fn as_any(&self) -> &dyn Any;
 }
 
 impl SessionBuilder {
@@ -193,10 +212,30 @@ impl Session {
         self.source_map.clone()
     }
 
+    /// Returns the token cache, creating it with `init` on first use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cache was created with a different type.
+    pub fn token_cache<T: SourceCache>(&self, init: impl FnOnce() -> T) -> &T {
+        let cache = self.token_cache.get_or_init(|| Box::new(init()));
+        cache.as_any().downcast_ref().expect("token cache type mismatch")
+    }
+
+    /// Removes the cached data of the given file, such as its lexed tokens, returning `true` if
+    /// there was an entry.
+    ///
+    /// This should be called when the file is edited.
+    pub fn invalidate(&self, name: &FileName) -> bool {
+        self.token_cache.get().is_some_and(|cache| cache.invalidate(name))
+    }
+
     /// Returns `true` if compilation should stop after the given stage.
     #[inline]
     pub fn stop_after(&self, stage: CompilerStage) -> bool {
-        self.opts.stop_after >= Some(stage)
+        let stop_after =
+            if self.opts.parse_only { Some(CompilerStage::Parsed) } else { self.opts.stop_after };
+        stop_after >= Some(stage)
     }
 
     /// Returns the number of threads to use for parallelism.
@@ -378,6 +417,20 @@ mod tests {
         assert!(err.to_string().contains("error: test"), "{err:?}");
     }
 
+    #[test]
+    fn color() {
+        let emit = |color| {
+            let sess = Session::builder().with_buffer_emitter(color).build();
+            sess.dcx.err("test").note("note").emit();
+            sess.dcx.emitted_diagnostics().unwrap().to_string()
+        };
+        let never = emit(ColorChoice::Never);
+        assert!(never.contains("error: test"), "{never:?}");
+        assert!(!never.contains('\x1b'), "{never:?}");
+        let always = emit(ColorChoice::Always);
+        assert!(always.contains('\x1b'), "{always:?}");
+    }
+
     #[test]
     fn enter() {
         #[track_caller]
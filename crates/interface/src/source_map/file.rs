@@ -1,3 +1,4 @@
+use super::ColumnEncoding;
 use crate::{pos::RelativeBytePos, BytePos, CharPos};
 use std::{
     fmt, io,
@@ -261,6 +262,23 @@ impl SourceFile {
         &self.lines
     }
 
+    /// Returns the absolute positions of the beginnings of all lines in this file.
+    ///
+    /// The first line always starts at [`start_pos`](Self::start_pos), even if the file is
+    /// empty. A trailing newline does not start a new line.
+    pub fn line_starts(&self) -> impl ExactSizeIterator<Item = BytePos> + '_ {
+        self.lines().iter().map(|&pos| self.absolute_position(pos))
+    }
+
+    /// Returns the text of the line at the given 0-based line index, without its line terminator.
+    ///
+    /// Both `\n` and `\r\n` terminators are stripped; the source itself is not normalized, so
+    /// [`get_line`](Self::get_line) keeps the trailing `\r` of CRLF lines.
+    pub fn line(&self, line_number: usize) -> Option<&str> {
+        let line = self.get_line(line_number)?;
+        Some(line.strip_suffix('\r').unwrap_or(line))
+    }
+
     pub fn count_lines(&self) -> usize {
         self.lines().len()
     }
@@ -373,6 +391,59 @@ impl SourceFile {
         }
     }
 
+    /// Looks up the file's (1-based) line and column numbers for a given `RelativeBytePos`,
+    /// counting columns in the given unit.
+    pub fn lookup_line_col(
+        &self,
+        pos: RelativeBytePos,
+        encoding: ColumnEncoding,
+    ) -> (usize, usize) {
+        let Some(line) = self.lookup_line(pos) else { return (1, 1) };
+        let line_start = self.lines()[line].to_usize();
+        let prefix = &self.src[line_start..pos.to_usize()];
+        let col = match encoding {
+            ColumnEncoding::Char => prefix.chars().count(),
+            ColumnEncoding::Utf16 => prefix.encode_utf16().count(),
+        };
+        (line + 1, col + 1)
+    }
+
+    /// Converts (1-based) line and column numbers, counted in the given unit, to a position in this
+    /// file. This is the inverse of [`lookup_line_col`](Self::lookup_line_col).
+    ///
+    /// Returns `None` if the line or column is out of range, or if the column is not on a `char`
+    /// boundary, such as in the middle of a UTF-16 surrogate pair.
+    pub fn line_col_to_pos(
+        &self,
+        line: usize,
+        col: usize,
+        encoding: ColumnEncoding,
+    ) -> Option<RelativeBytePos> {
+        let line_index = line.checked_sub(1)?;
+        let mut remaining = col.checked_sub(1)?;
+        let line_start = match self.lines().get(line_index) {
+            Some(start) => start.to_usize(),
+            // Positions at the end of an empty file are on the first line.
+            None if line_index == 0 && self.lines().is_empty() => 0,
+            None => return None,
+        };
+        // The last line extends to the end of the file, including a trailing newline.
+        let is_last = line_index + 1 >= self.lines().len();
+        let line_end = self.lines().get(line_index + 1).map_or(self.src.len(), |x| x.to_usize());
+        let mut pos = line_start;
+        for ch in self.src[line_start..line_end].chars() {
+            if remaining == 0 {
+                return Some(RelativeBytePos::from_usize(pos));
+            }
+            remaining = remaining.checked_sub(match encoding {
+                ColumnEncoding::Char => 1,
+                ColumnEncoding::Utf16 => ch.len_utf16(),
+            })?;
+            pos += ch.len_utf8();
+        }
+        (remaining == 0 && is_last).then(|| RelativeBytePos::from_usize(pos))
+    }
+
     /// Gets a line from the list of pre-computed line-beginnings.
     /// The line number here is 0-based.
     pub fn get_line(&self, line_number: usize) -> Option<&str> {
@@ -22,6 +22,8 @@ pub enum ResolveError {
     ReadFile(PathBuf, #[source] io::Error),
     #[error("file {0} not found")]
     NotFound(PathBuf),
+    #[error("file {0} is too large: {1} bytes, the maximum is {2} bytes")]
+    FileTooLarge(PathBuf, u64, u64),
     #[error("multiple files match {}: {}", .0.display(), .1.iter().map(|f| f.name.display()).format(", "))]
     MultipleMatches(PathBuf, Vec<Arc<SourceFile>>),
 }
@@ -29,12 +31,23 @@ pub enum ResolveError {
 pub struct FileResolver<'a> {
     source_map: &'a SourceMap,
     import_paths: Vec<(Option<PathBuf>, PathBuf)>,
+    max_file_size: u64,
 }
 
 impl<'a> FileResolver<'a> {
+    /// The default maximum size of a file that can be loaded, in bytes: 16 MiB.
+    pub const DEFAULT_MAX_FILE_SIZE: u64 = 16 * 1024 * 1024;
+
     /// Creates a new file resolver.
     pub fn new(source_map: &'a SourceMap) -> Self {
-        Self { source_map, import_paths: Vec::new() }
+        Self { source_map, import_paths: Vec::new(), max_file_size: Self::DEFAULT_MAX_FILE_SIZE }
+    }
+
+    /// Sets the maximum size of a file that can be loaded, in bytes.
+    ///
+    /// Larger files are rejected with [`ResolveError::FileTooLarge`] before being read.
+    pub fn set_max_file_size(&mut self, max_file_size: u64) {
+        self.max_file_size = max_file_size;
     }
 
     /// Returns the source map.
@@ -164,6 +177,12 @@ impl<'a> FileResolver<'a> {
     #[instrument(level = "debug", skip_all)]
     pub fn try_file(&self, path: &Path) -> Result<Option<Arc<SourceFile>>, ResolveError> {
         let cache_path = path.normalize();
+        if let Ok(metadata) = std::fs::metadata(&cache_path) {
+            if metadata.len() > self.max_file_size {
+                let (len, max) = (metadata.len(), self.max_file_size);
+                return Err(ResolveError::FileTooLarge(cache_path, len, max));
+            }
+        }
         if let Ok(file) = self.source_map().load_file(&cache_path) {
             trace!("loaded from cache");
             return Ok(Some(file));
@@ -190,3 +209,38 @@ impl<'a> FileResolver<'a> {
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_paths_in_order() {
+        let dir = std::env::temp_dir().join(format!("solar-file-resolver-{}", std::process::id()));
+        let (src, lib_a, lib_b) = (dir.join("src"), dir.join("lib_a"), dir.join("lib_b"));
+        for (path, contents) in [
+            (src.join("main.sol"), "import \"dep.sol\";"),
+            (lib_a.join("only_a.sol"), "contract A {}"),
+            (lib_b.join("dep.sol"), "contract B {}"),
+        ] {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+
+        let sm = SourceMap::empty();
+        let mut resolver = FileResolver::new(&sm);
+        assert!(resolver.add_import_path(lib_a));
+        assert!(resolver.add_import_path(lib_b.clone()));
+        assert!(!resolver.add_import_path(lib_b));
+
+        let parent = src.join("main.sol");
+        let dep = resolver.resolve_file(Path::new("dep.sol"), Some(&parent)).unwrap();
+        assert_eq!(&**dep.src, "contract B {}");
+        let only_a = resolver.resolve_file(Path::new("only_a.sol"), Some(&parent)).unwrap();
+        assert_eq!(&**only_a.src, "contract A {}");
+        let err = resolver.resolve_file(Path::new("missing.sol"), Some(&parent)).unwrap_err();
+        assert!(matches!(err, ResolveError::NotFound(_)), "{err:?}");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
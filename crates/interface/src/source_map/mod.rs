@@ -64,6 +64,33 @@ pub struct Loc {
     pub col_display: usize,
 }
 
+/// The (1-based) line and column range of a [`Span`].
+///
+/// Returned by [`SourceMap::span_to_location`].
+#[derive(Clone, Debug)]
+pub struct SpanLocation {
+    /// Information about the original source.
+    pub file: Arc<SourceFile>,
+    /// The (1-based) line number of the start of the span.
+    pub line_start: usize,
+    /// The (1-based) column number of the start of the span.
+    pub col_start: usize,
+    /// The (1-based) line number of the end of the span.
+    pub line_end: usize,
+    /// The (1-based) column number of the end of the span, exclusive.
+    pub col_end: usize,
+}
+
+/// The unit in which columns are counted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColumnEncoding {
+    /// Unicode scalar values, i.e. `char`s.
+    #[default]
+    Char,
+    /// UTF-16 code units, as used by the Language Server Protocol.
+    Utf16,
+}
+
 // Used to be structural records.
 #[derive(Debug)]
 pub struct SourceFileAndLine {
@@ -267,6 +294,97 @@ impl SourceMap {
         Loc { file: sf, line, col, col_display }
     }
 
+    /// Looks up the (1-based) line and column numbers of a `BytePos`.
+    ///
+    /// Columns are counted in `char`s. See [`lookup_line_col_with`](Self::lookup_line_col_with)
+    /// to count in a different unit.
+    pub fn lookup_line_col(&self, pos: BytePos) -> (usize, usize) {
+        self.lookup_line_col_with(pos, ColumnEncoding::Char)
+    }
+
+    /// Looks up the (1-based) line and column numbers of a `BytePos`, counting columns in the
+    /// given unit.
+    pub fn lookup_line_col_with(&self, pos: BytePos, encoding: ColumnEncoding) -> (usize, usize) {
+        let sf = self.lookup_source_file(pos);
+        sf.lookup_line_col(sf.relative_position(pos), encoding)
+    }
+
+    /// Returns the (1-based) line and column range of the given `Span`.
+    ///
+    /// Columns are counted in `char`s. See [`span_to_location_with`](Self::span_to_location_with)
+    /// to count in a different unit.
+    pub fn span_to_location(&self, span: Span) -> SpanLocation {
+        self.span_to_location_with(span, ColumnEncoding::Char)
+    }
+
+    /// Returns the (1-based) line and column range of the given `Span`, counting columns in the
+    /// given unit.
+    pub fn span_to_location_with(&self, span: Span, encoding: ColumnEncoding) -> SpanLocation {
+        let file = self.lookup_source_file(span.lo());
+        debug_assert!(file.contains(span.hi()), "span crosses file boundaries: {span:?}");
+        let (line_start, col_start) =
+            file.lookup_line_col(file.relative_position(span.lo()), encoding);
+        let (line_end, col_end) = file.lookup_line_col(file.relative_position(span.hi()), encoding);
+        SpanLocation { file, line_start, col_start, line_end, col_end }
+    }
+
+    /// Converts (1-based) line and column numbers in the given file to a `BytePos`. This is the
+    /// inverse of [`lookup_line_col`](Self::lookup_line_col).
+    ///
+    /// Columns are counted in `char`s. See [`line_col_to_pos_with`](Self::line_col_to_pos_with)
+    /// to count in a different unit.
+    ///
+    /// Returns `None` if the line or column is out of range.
+    pub fn line_col_to_pos(&self, file: &SourceFile, line: usize, col: usize) -> Option<BytePos> {
+        self.line_col_to_pos_with(file, line, col, ColumnEncoding::Char)
+    }
+
+    /// Converts (1-based) line and column numbers in the given file to a `BytePos`, counting
+    /// columns in the given unit.
+    ///
+    /// Returns `None` if the line or column is out of range, or if the column is not on a `char`
+    /// boundary.
+    pub fn line_col_to_pos_with(
+        &self,
+        file: &SourceFile,
+        line: usize,
+        col: usize,
+        encoding: ColumnEncoding,
+    ) -> Option<BytePos> {
+        let pos = file.line_col_to_pos(line, col, encoding)?;
+        Some(file.absolute_position(pos))
+    }
+
+    /// Creates a `Span` in the given file from (1-based) `(line, column)` start and end
+    /// positions. This is the inverse of [`span_to_location`](Self::span_to_location).
+    ///
+    /// Columns are counted in `char`s. See [`span_from_range_with`](Self::span_from_range_with)
+    /// to count in a different unit.
+    ///
+    /// Returns `None` if either position is out of range, or if `end` is before `start`.
+    pub fn span_from_range(
+        &self,
+        file: &SourceFile,
+        start: (usize, usize),
+        end: (usize, usize),
+    ) -> Option<Span> {
+        self.span_from_range_with(file, start, end, ColumnEncoding::Char)
+    }
+
+    /// Creates a `Span` in the given file from (1-based) `(line, column)` start and end
+    /// positions, counting columns in the given unit.
+    pub fn span_from_range_with(
+        &self,
+        file: &SourceFile,
+        start: (usize, usize),
+        end: (usize, usize),
+        encoding: ColumnEncoding,
+    ) -> Option<Span> {
+        let lo = self.line_col_to_pos_with(file, start.0, start.1, encoding)?;
+        let hi = self.line_col_to_pos_with(file, end.0, end.1, encoding)?;
+        (lo <= hi).then(|| Span::new(lo, hi))
+    }
+
     /// If the corresponding `SourceFile` is empty, does not return a line number.
     pub fn lookup_line(&self, pos: BytePos) -> Result<SourceFileAndLine, Arc<SourceFile>> {
         let f = self.lookup_source_file(pos);
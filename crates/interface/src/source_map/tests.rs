@@ -625,3 +625,111 @@ fn read_binary_file_handles_lying_stat() {
     let bin = RealFileLoader.read_binary_file(kernel_max).unwrap();
     assert_eq!(&real[..], &bin[..]);
 }
+
+/// Tests `lookup_line_col` and `span_to_location` in the presence of emoji and CJK characters.
+#[test]
+fn line_col_multibyte() {
+    let sm = SourceMap::empty();
+    // "😀" is 4 bytes, 1 char and 2 UTF-16 code units.
+    // "中" and "文" are 3 bytes, 1 char and 1 UTF-16 code unit each.
+    let src = "a😀b\n中文 = x;\n😀😀 y";
+    sm.new_dummy_source_file(PathBuf::from("blork.rs"), src.to_string()).unwrap();
+    let pos = |s: &str| BytePos(src.find(s).unwrap() as u32);
+
+    assert_eq!(sm.lookup_line_col(BytePos(0)), (1, 1));
+    assert_eq!(sm.lookup_line_col(pos("b")), (1, 3));
+    assert_eq!(sm.lookup_line_col(pos("=")), (2, 4));
+    assert_eq!(sm.lookup_line_col(pos("y")), (3, 4));
+
+    assert_eq!(sm.lookup_line_col_with(pos("b"), ColumnEncoding::Utf16), (1, 4));
+    assert_eq!(sm.lookup_line_col_with(pos("="), ColumnEncoding::Utf16), (2, 4));
+    assert_eq!(sm.lookup_line_col_with(pos("y"), ColumnEncoding::Utf16), (3, 6));
+
+    let span = Span::new(pos("中"), pos("x") + 1);
+    let loc = sm.span_to_location(span);
+    assert_eq!(loc.file.name, Path::new("blork.rs"));
+    assert_eq!((loc.line_start, loc.col_start, loc.line_end, loc.col_end), (2, 1, 2, 7));
+
+    let span = Span::new(pos("b"), pos("y") + 1);
+    let loc = sm.span_to_location_with(span, ColumnEncoding::Utf16);
+    assert_eq!((loc.line_start, loc.col_start, loc.line_end, loc.col_end), (1, 4, 3, 7));
+}
+
+/// Tests that `line_col_to_pos` is the inverse of `lookup_line_col`.
+#[test]
+fn line_col_round_trip() {
+    let sm = SourceMap::empty();
+    sm.new_dummy_source_file(PathBuf::from("a.sol"), "x".to_string()).unwrap();
+    let srcs = ["a😀b\n中文 = x;\n😀😀 y", "first\r\nsecond\r\n\r\nlast\r\n", "a\n\nb", ""];
+    for (i, src) in srcs.into_iter().enumerate() {
+        let path = PathBuf::from(format!("{i}.sol"));
+        let file = sm.new_dummy_source_file(path, src.to_string()).unwrap();
+        for encoding in [ColumnEncoding::Char, ColumnEncoding::Utf16] {
+            for (offset, _) in src.char_indices().chain([(src.len(), ' ')]) {
+                let pos = file.start_pos + BytePos(offset as u32);
+                let (line, col) = sm.lookup_line_col_with(pos, encoding);
+                let back = sm.line_col_to_pos_with(&file, line, col, encoding);
+                assert_eq!(back, Some(pos), "{src:?} {offset} {encoding:?} ({line}, {col})");
+            }
+        }
+    }
+}
+
+#[test]
+fn line_col_to_pos() {
+    let sm = SourceMap::empty();
+    let src = "a😀b\n中文 = x;\n";
+    let file = sm.new_dummy_source_file(PathBuf::from("blork.rs"), src.to_string()).unwrap();
+    let pos = |s: &str| BytePos(src.find(s).unwrap() as u32);
+
+    assert_eq!(sm.line_col_to_pos(&file, 1, 1), Some(BytePos(0)));
+    assert_eq!(sm.line_col_to_pos(&file, 1, 3), Some(pos("b")));
+    assert_eq!(sm.line_col_to_pos_with(&file, 1, 4, ColumnEncoding::Utf16), Some(pos("b")));
+    assert_eq!(sm.line_col_to_pos(&file, 2, 4), Some(pos("=")));
+    // End of the first line, before the newline.
+    assert_eq!(sm.line_col_to_pos(&file, 1, 4), Some(pos("\n")));
+    // End of the file, after the trailing newline.
+    assert_eq!(sm.line_col_to_pos(&file, 2, 9), Some(BytePos(src.len() as u32)));
+
+    // Out of range.
+    assert_eq!(sm.line_col_to_pos(&file, 0, 1), None);
+    assert_eq!(sm.line_col_to_pos(&file, 1, 0), None);
+    assert_eq!(sm.line_col_to_pos(&file, 1, 5), None);
+    assert_eq!(sm.line_col_to_pos(&file, 2, 10), None);
+    assert_eq!(sm.line_col_to_pos(&file, 3, 1), None);
+    // In the middle of a surrogate pair.
+    assert_eq!(sm.line_col_to_pos_with(&file, 1, 3, ColumnEncoding::Utf16), None);
+
+    let span = sm.span_from_range(&file, (1, 3), (2, 7)).unwrap();
+    assert_eq!(sm.span_to_snippet(span).unwrap(), "b\n中文 = x");
+    let loc = sm.span_to_location(span);
+    assert_eq!((loc.line_start, loc.col_start, loc.line_end, loc.col_end), (1, 3, 2, 7));
+    assert_eq!(sm.span_from_range(&file, (2, 1), (1, 1)), None);
+    assert_eq!(sm.span_from_range(&file, (1, 1), (5, 1)), None);
+}
+
+#[test]
+fn line_starts_and_line() {
+    let sm = SourceMap::empty();
+    sm.new_dummy_source_file(PathBuf::from("a.sol"), "x".to_string()).unwrap();
+
+    // CRLF line endings are kept in the source, but not in the returned lines.
+    let src = "first\r\nsecond\r\n\r\nlast\r\n";
+    let file = sm.new_dummy_source_file(PathBuf::from("crlf.sol"), src.to_string()).unwrap();
+    let start = file.start_pos;
+    let starts = file.line_starts().map(|pos| (pos - start).to_usize()).collect::<Vec<_>>();
+    assert_eq!(starts, [0, 7, 15, 17]);
+    let lines = (0..file.count_lines()).map(|i| file.line(i).unwrap()).collect::<Vec<_>>();
+    assert_eq!(lines, ["first", "second", "", "last"]);
+    assert_eq!(file.get_line(0), Some("first\r"));
+    assert_eq!(file.line(4), None);
+
+    // Without a trailing newline, the last line extends to the end of the file.
+    let src = "first\nlast";
+    let file = sm.new_dummy_source_file(PathBuf::from("no_nl.sol"), src.to_string()).unwrap();
+    let starts = file.line_starts().map(|pos| (pos - file.start_pos).to_usize());
+    assert_eq!(starts.collect::<Vec<_>>(), [0, 6]);
+    assert_eq!(file.line(0), Some("first"));
+    assert_eq!(file.line(1), Some("last"));
+    assert_eq!(file.line(2), None);
+}
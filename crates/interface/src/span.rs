@@ -124,6 +124,14 @@ impl Span {
         self.lo() <= other.lo() && other.hi() <= self.hi()
     }
 
+    /// Returns `true` if `pos` is inside this span.
+    ///
+    /// The end of the span is exclusive, except for empty spans, which contain only their start.
+    #[inline]
+    pub fn contains_pos(self, pos: BytePos) -> bool {
+        self.lo() <= pos && (pos < self.hi() || pos == self.lo())
+    }
+
     /// Returns `true` if `self` touches `other`.
     #[inline]
     pub fn overlaps(self, other: Self) -> bool {
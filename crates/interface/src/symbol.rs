@@ -209,6 +209,11 @@ impl Symbol {
         SessionGlobals::with(|g| g.symbol_interner.intern(string))
     }
 
+    /// Returns statistics about the symbol interner of the current session.
+    pub fn interner_stats() -> InternerStats {
+        SessionGlobals::with(|g| g.symbol_interner.stats())
+    }
+
     /// "Specialization" of [`ToString`] using [`as_str`](Self::as_str).
     #[inline]
     #[allow(clippy::inherent_to_string_shadow_display)]
@@ -353,6 +358,17 @@ impl fmt::Display for Symbol {
     }
 }
 
+/// Symbol interner statistics. See [`Symbol::interner_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InternerStats {
+    /// The number of interned symbols, including the pre-interned ones.
+    pub symbols: usize,
+    /// The total length of all the interned strings, in bytes.
+    pub bytes: usize,
+    /// The number of bytes currently allocated by the interner.
+    pub memory_usage: usize,
+}
+
 type InternerInner = LassoInterner;
 
 /// Symbol interner.
@@ -379,6 +395,10 @@ impl Interner {
     fn get(&self, symbol: Symbol) -> &str {
         self.0.get(symbol)
     }
+
+    fn stats(&self) -> InternerStats {
+        self.0.stats()
+    }
 }
 
 // TODO: We could finalize the interner after parsing to a `RodeoResolver`, making it read-only.
@@ -412,6 +432,14 @@ impl LassoInterner {
     fn get(&self, symbol: Symbol) -> &str {
         self.0.resolve(&symbol)
     }
+
+    fn stats(&self) -> InternerStats {
+        InternerStats {
+            symbols: self.0.len(),
+            bytes: self.0.strings().map(str::len).sum(),
+            memory_usage: self.0.current_memory_usage(),
+        }
+    }
 }
 
 unsafe impl lasso::Key for Symbol {
@@ -756,6 +784,8 @@ symbols! {
         Caller:         "caller",
         Callvalue:      "callvalue",
         Chainid:        "chainid",
+        Codecopy:       "codecopy",
+        Codesize:       "codesize",
         Coinbase:       "coinbase",
         Create:         "create",
         Create2:        "create2",
@@ -856,6 +886,9 @@ symbols! {
         concat,
         creationCode,
         data,
+        datacopy,
+        dataoffset,
+        datasize,
         decode,
         ecrecover,
         encode,
@@ -916,6 +949,28 @@ mod tests {
         assert_eq!(i.intern("dog"), Symbol::new(0));
     }
 
+    #[test]
+    fn interner_stats() {
+        let i = Interner::prefill(&[]);
+        assert_eq!(i.stats().symbols, 0);
+        i.intern("dog");
+        i.intern("cat");
+        i.intern("dog");
+        let stats = i.stats();
+        assert_eq!(stats.symbols, 2);
+        assert_eq!(stats.bytes, 6);
+        assert!(stats.memory_usage >= stats.bytes);
+
+        crate::enter(|| {
+            let before = Symbol::interner_stats();
+            assert!(before.symbols > 0);
+            Symbol::intern("a_symbol_that_is_not_preinterned");
+            let after = Symbol::interner_stats();
+            assert_eq!(after.symbols, before.symbols + 1);
+            assert_eq!(after.bytes, before.bytes + 32);
+        });
+    }
+
     #[test]
     fn defaults() {
         assert_eq!(Symbol::DUMMY, Symbol::new(0));
@@ -0,0 +1,351 @@
+//! Lossless concrete syntax tree.
+//!
+//! Unlike the AST, the CST keeps every byte of the source: tokens, comments, whitespace, and any
+//! text that the lexer could not turn into a token. The only structure it recovers is the nesting
+//! of balanced delimiters, which makes it suitable as a base for formatters and other tools that
+//! need to reproduce the input exactly.
+
+use crate::{Lexer, Parser};
+use solar_ast::token::{Delimiter, TokenKind};
+use solar_interface::{
+    diagnostics::ErrorGuaranteed, source_map::SourceFile, BytePos, Session, Span,
+};
+use std::fmt::{self, Write};
+
+/// A node in the concrete syntax tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CstNode {
+    /// The kind of the node.
+    pub kind: CstNodeKind,
+    /// The span of the node, covering all of its children.
+    pub span: Span,
+    /// The children of the node, in source order.
+    pub children: Vec<CstElement>,
+}
+
+/// The kind of a [`CstNode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CstNodeKind {
+    /// The whole source file.
+    SourceUnit,
+    /// A delimited group, including its opening and closing delimiters.
+    ///
+    /// The closing delimiter is missing if the group is not closed before the end of the file.
+    Delimited(Delimiter),
+}
+
+/// A child of a [`CstNode`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CstElement {
+    /// A nested node.
+    Node(CstNode),
+    /// A leaf token.
+    Token(CstToken),
+}
+
+/// A leaf in the concrete syntax tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CstToken {
+    /// The kind of the token.
+    pub kind: CstTokenKind,
+    /// The span of the token.
+    pub span: Span,
+}
+
+/// The kind of a [`CstToken`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CstTokenKind {
+    /// A token produced by the lexer, including comments.
+    Token(TokenKind),
+    /// A run of whitespace.
+    Whitespace,
+    /// Text that was skipped by the lexer, such as a byte order mark or invalid characters.
+    Skipped,
+}
+
+impl CstElement {
+    /// Returns the span of the element.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Node(node) => node.span,
+            Self::Token(token) => token.span,
+        }
+    }
+}
+
+impl CstNode {
+    /// Lexes the given source file into a concrete syntax tree.
+    ///
+    /// Lexer errors are emitted to the session's diagnostic context as usual. Returns an error if
+    /// delimiters are nested deeper than
+    /// [`Opts::max_parse_depth`](solar_interface::config::Opts::max_parse_depth).
+    ///
+    /// Note that the source file must be added to the source map before calling this function.
+    pub fn from_source_file(sess: &Session, file: &SourceFile) -> Result<Self, ErrorGuaranteed> {
+        let max_depth = sess.opts.max_parse_depth.unwrap_or(Parser::DEFAULT_MAX_DEPTH);
+        let mut builder = Builder { file, pos: file.start_pos, stack: Vec::new() };
+        builder.open(CstNodeKind::SourceUnit, file.start_pos);
+        for token in Lexer::from_source_file(sess, file) {
+            builder.gap(token.span.lo());
+            match token.kind {
+                TokenKind::OpenDelim(_) if builder.stack.len() > max_depth => {
+                    let msg = "delimiters nested too deeply";
+                    let note = format!("the maximum nesting depth is {max_depth}");
+                    return Err(sess.dcx.err(msg).span(token.span).note(note).emit());
+                }
+                TokenKind::OpenDelim(delim) => {
+                    builder.open(CstNodeKind::Delimited(delim), token.span.lo());
+                    builder.token(CstTokenKind::Token(token.kind), token.span);
+                }
+                TokenKind::CloseDelim(delim)
+                    if builder.stack.iter().any(|n| n.kind == CstNodeKind::Delimited(delim)) =>
+                {
+                    // Close any unbalanced groups that were opened inside of this one.
+                    while builder.stack.last().unwrap().kind != CstNodeKind::Delimited(delim) {
+                        builder.close();
+                    }
+                    builder.token(CstTokenKind::Token(token.kind), token.span);
+                    builder.close();
+                }
+                _ => builder.token(CstTokenKind::Token(token.kind), token.span),
+            }
+        }
+        builder.gap(file.end_position());
+        while builder.stack.len() > 1 {
+            builder.close();
+        }
+        let mut root = builder.stack.pop().unwrap();
+        root.span = root.span.with_hi(file.end_position());
+        Ok(root)
+    }
+
+    /// Returns the source text covered by this node.
+    ///
+    /// For a node built with [`from_source_file`](Self::from_source_file), this is exactly the
+    /// text of the file.
+    pub fn to_source(&self, file: &SourceFile) -> String {
+        let mut out = String::with_capacity((self.span.hi() - self.span.lo()).to_usize());
+        self.write_source(file, &mut out);
+        out
+    }
+
+    fn write_source(&self, file: &SourceFile, out: &mut String) {
+        self.walk(|_, element| {
+            if let Element::Token(token) = element {
+                out.push_str(text(file, token.span));
+            }
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    /// Writes the tree to `out`, one element per line, indenting children by two spaces.
+    ///
+    /// Offsets are relative to the start of `file`.
+    pub fn dump(&self, file: &SourceFile, out: &mut impl Write) -> fmt::Result {
+        self.dump_inner(file, out)
+    }
+
+    fn dump_inner(&self, file: &SourceFile, out: &mut impl Write) -> fmt::Result {
+        let range = |span: Span| {
+            let lo = span.lo() - file.start_pos;
+            let hi = span.hi() - file.start_pos;
+            format!("{}..{}", lo.0, hi.0)
+        };
+        self.walk(|depth, element| match element {
+            Element::Node(node) => {
+                writeln!(
+                    out,
+                    "{:depth$}{:?} {}",
+                    "",
+                    node.kind,
+                    range(node.span),
+                    depth = depth * 2
+                )
+            }
+            Element::Token(token) => writeln!(
+                out,
+                "{:depth$}{:?} {} {:?}",
+                "",
+                token.kind,
+                range(token.span),
+                text(file, token.span),
+                depth = depth * 2,
+            ),
+        })
+    }
+
+    /// Calls `f` with every element of the tree in source order, including `self`, along with its
+    /// depth.
+    ///
+    /// Uses an explicit stack, since the tree can be nested arbitrarily deep.
+    fn walk<'a>(&'a self, mut f: impl FnMut(usize, Element<'a>) -> fmt::Result) -> fmt::Result {
+        f(0, Element::Node(self))?;
+        let mut stack = vec![self.children.iter()];
+        while let Some(children) = stack.last_mut() {
+            let next = children.next();
+            let depth = stack.len();
+            match next {
+                Some(CstElement::Node(node)) => {
+                    f(depth, Element::Node(node))?;
+                    stack.push(node.children.iter());
+                }
+                Some(CstElement::Token(token)) => f(depth, Element::Token(token))?,
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A borrowed [`CstElement`].
+#[derive(Clone, Copy)]
+enum Element<'a> {
+    Node(&'a CstNode),
+    Token(&'a CstToken),
+}
+
+fn text(file: &SourceFile, span: Span) -> &str {
+    let lo = (span.lo() - file.start_pos).to_usize();
+    let hi = (span.hi() - file.start_pos).to_usize();
+    &file.src[lo..hi]
+}
+
+struct Builder<'a> {
+    file: &'a SourceFile,
+    /// The end of the last element that was pushed.
+    pos: BytePos,
+    /// The nodes that are currently open, innermost last.
+    stack: Vec<CstNode>,
+}
+
+impl Builder<'_> {
+    fn open(&mut self, kind: CstNodeKind, lo: BytePos) {
+        self.stack.push(CstNode { kind, span: Span::new(lo, lo), children: Vec::new() });
+    }
+
+    fn close(&mut self) {
+        let mut node = self.stack.pop().unwrap();
+        node.span = node.span.with_hi(self.pos);
+        self.stack.last_mut().unwrap().children.push(CstElement::Node(node));
+    }
+
+    fn token(&mut self, kind: CstTokenKind, span: Span) {
+        self.stack.last_mut().unwrap().children.push(CstElement::Token(CstToken { kind, span }));
+        self.pos = span.hi();
+    }
+
+    /// Pushes the text between the last element and `hi`, splitting it into whitespace and
+    /// skipped runs.
+    fn gap(&mut self, hi: BytePos) {
+        let span = Span::new(self.pos, hi);
+        let mut start = self.pos;
+        let mut current: Option<CstTokenKind> = None;
+        for (i, c) in text(self.file, span).char_indices() {
+            let kind = if crate::lexer::is_whitespace(c) {
+                CstTokenKind::Whitespace
+            } else {
+                CstTokenKind::Skipped
+            };
+            if current.as_ref().is_some_and(|current| *current != kind) {
+                let pos = span.lo() + BytePos::from_usize(i);
+                self.token(current.replace(kind).unwrap(), Span::new(start, pos));
+                start = pos;
+            } else {
+                current = Some(kind);
+            }
+        }
+        if let Some(kind) = current {
+            self.token(kind, Span::new(start, hi));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solar_interface::source_map::FileName;
+
+    #[track_caller]
+    fn with_cst(src: &str, f: impl FnOnce(&SourceFile, &CstNode)) {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let file = sess
+                .source_map()
+                .new_source_file(FileName::Custom("test".into()), || Ok(src.to_string()))
+                .unwrap();
+            let cst = CstNode::from_source_file(&sess, &file).unwrap();
+            f(&file, &cst);
+        });
+    }
+
+    #[test]
+    fn round_trip() {
+        let srcs = [
+            "",
+            " \n\t",
+            "\u{feff}contract C {}\r\n",
+            "// SPDX-License-Identifier: MIT\npragma solidity ^0.8.0;\n\n/// Doc.\ncontract C {\n    /* block */ uint256 x = 1; // trailing\n    function f(uint256[] memory a) public { a[0] = x; }\n}\n",
+            "function f() { assembly { let x := add(1, 2) } }",
+            "contract C { function f() { ( ]",
+            "contract C } {",
+        ];
+        for src in srcs {
+            with_cst(src, |file, cst| {
+                assert_eq!(cst.to_source(file), src);
+                assert_eq!(cst.span, Span::new(file.start_pos, file.end_position()));
+            });
+        }
+    }
+
+    #[test]
+    fn nested_too_deeply() {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let depth = Parser::DEFAULT_MAX_DEPTH;
+            let src = format!("{}{}", "(".repeat(depth), ")".repeat(depth));
+            let file = sess
+                .source_map()
+                .new_source_file(FileName::Custom("ok".into()), || Ok(src.clone()))
+                .unwrap();
+            let cst = CstNode::from_source_file(&sess, &file).unwrap();
+            assert_eq!(cst.to_source(&file), src);
+
+            let src = "(".repeat(depth + 1);
+            let file = sess
+                .source_map()
+                .new_source_file(FileName::Custom("deep".into()), || Ok(src))
+                .unwrap();
+            assert!(CstNode::from_source_file(&sess, &file).is_err());
+        });
+    }
+
+    #[test]
+    fn structure() {
+        let src = "f(a, { b }) // c";
+        with_cst(src, |file, cst| {
+            let mut dump = String::new();
+            cst.dump(file, &mut dump).unwrap();
+            let expected = r#"SourceUnit 0..16
+  Token(Ident("f")) 0..1 "f"
+  Delimited(Parenthesis) 1..11
+    Token(OpenDelim(Parenthesis)) 1..2 "("
+    Token(Ident("a")) 2..3 "a"
+    Token(Comma) 3..4 ","
+    Whitespace 4..5 " "
+    Delimited(Brace) 5..10
+      Token(OpenDelim(Brace)) 5..6 "{"
+      Whitespace 6..7 " "
+      Token(Ident("b")) 7..8 "b"
+      Whitespace 8..9 " "
+      Token(CloseDelim(Brace)) 9..10 "}"
+    Token(CloseDelim(Parenthesis)) 10..11 ")"
+  Whitespace 11..12 " "
+  Token(Comment(false, Line, " c")) 12..16 "// c"
+"#;
+            assert_eq!(dump, expected);
+        });
+    }
+}
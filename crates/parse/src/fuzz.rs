@@ -0,0 +1,91 @@
+//! Entry points for fuzzing.
+//!
+//! These functions set up their own [`Session`] and return diagnostics as data instead of emitting
+//! them, which makes them suitable for fuzz targets that only check for panics.
+
+use crate::{Lexer, Parser};
+use solar_ast::{token::Token, Arena, SourceUnit};
+use solar_interface::{
+    diagnostics::{Diag, ErrorGuaranteed},
+    source_map::FileName,
+    ColorChoice, Session,
+};
+
+/// Lexes `src`, returning the tokens, or the diagnostics if any errors were emitted.
+///
+/// Session globals are set up internally if they are not already set. Note that the symbols
+/// contained in the returned tokens can only be resolved if the globals were already set when
+/// calling this function, for example with [`solar_interface::enter`].
+pub fn try_lex(src: &str) -> Result<Vec<Token>, Vec<Diag>> {
+    let sess = session();
+    sess.enter(|| {
+        let (tokens, diags) = sess.dcx.capture(|| Lexer::new(&sess, src).into_tokens());
+        match sess.dcx.has_errors() {
+            Ok(()) => Ok(tokens),
+            Err(_) => Err(diags),
+        }
+    })
+}
+
+/// Parses `src` as a Solidity source file and calls `f` with the parsed source unit, or returns
+/// the diagnostics if any errors were emitted.
+///
+/// Session globals are set up internally if they are not already set.
+pub fn try_parse<R>(src: &str, f: impl FnOnce(&SourceUnit<'_>) -> R) -> Result<R, Vec<Diag>> {
+    let sess = session();
+    sess.enter(|| {
+        let arena = Arena::new();
+        let (unit, diags) = sess.dcx.capture(|| -> Result<_, ErrorGuaranteed> {
+            let filename = FileName::Custom("fuzz".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, filename, src.into())?;
+            parser.parse_file().map_err(|e| e.emit())
+        });
+        match (unit, sess.dcx.has_errors()) {
+            (Ok(unit), Ok(())) => Ok(f(&unit)),
+            _ => Err(diags),
+        }
+    })
+}
+
+fn session() -> Session {
+    Session::builder().with_buffer_emitter(ColorChoice::Never).single_threaded().build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns `len` pseudo-random bytes using xorshift.
+    fn random_bytes(mut seed: u64, len: usize) -> Vec<u8> {
+        (0..len)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn random_input() {
+        for seed in 1..=256 {
+            let bytes = random_bytes(seed, 64);
+            let src = String::from_utf8_lossy(&bytes);
+            assert!(try_lex(&src).is_err(), "{src:?}");
+            assert!(try_parse(&src, |_| ()).is_err(), "{src:?}");
+        }
+    }
+
+    #[test]
+    fn valid_input() {
+        let src = "contract C { function f() public {} }";
+        solar_interface::enter(|| {
+            let tokens = try_lex(src).unwrap();
+            assert_eq!(tokens.len(), 11);
+            assert_eq!(tokens[1].ident().unwrap().as_str(), "C");
+        });
+        assert_eq!(try_parse(src, |unit| unit.items.len()).unwrap(), 1);
+        assert!(!try_parse("contract C {", |_| ()).unwrap_err().is_empty());
+    }
+}
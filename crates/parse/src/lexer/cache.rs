@@ -0,0 +1,184 @@
+use super::Lexer;
+use solar_ast::token::Token;
+use solar_data_structures::{map::FxHashMap, sync::Lock};
+use solar_interface::{
+    source_map::{FileName, SourceFile},
+    BytePos, Session, SourceCache,
+};
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// A cache of lexed token streams, keyed by source file name.
+///
+/// Cached tokens are only reused if the file's contents and position in the source map are
+/// unchanged, so that re-parsing an unmodified file skips lexing entirely. This is mainly useful
+/// for long-running tools, such as language servers, that parse the same files repeatedly.
+///
+/// The session's cache is returned by [`TokenCache::from_session`], and is used when parsing files
+/// with a [`Parser`](crate::Parser) created by
+/// [`from_source_file_cached`](crate::Parser::from_source_file_cached).
+///
+/// Tokens contain interned symbols, so a cache must only be used within the same session
+/// globals.
+///
+/// Token streams are only cached if no errors were emitted while lexing them, as diagnostics are
+/// not replayed on cache hits.
+#[derive(Debug, Default)]
+pub struct TokenCache {
+    entries: Lock<FxHashMap<FileName, CachedTokens>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct CachedTokens {
+    src: Arc<String>,
+    start_pos: BytePos,
+    tokens: Vec<Token>,
+}
+
+impl CachedTokens {
+    fn matches(&self, file: &SourceFile) -> bool {
+        self.start_pos == file.start_pos && self.src == file.src
+    }
+}
+
+impl TokenCache {
+    /// Creates a new, empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cache stored in the session, creating it on first use.
+    ///
+    /// Use [`Session::invalidate`] to remove the tokens of an edited file.
+    pub fn from_session(sess: &Session) -> &Self {
+        sess.token_cache(Self::new)
+    }
+
+    /// Returns the tokens of the given source file, lexing it only if it is not already cached.
+    ///
+    /// Note that the source file must be added to the source map before calling this function.
+    pub fn tokens(&self, sess: &Session, file: &SourceFile) -> Vec<Token> {
+        if let Some(entry) = self.entries.lock().get(&file.name) {
+            if entry.matches(file) {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return entry.tokens.clone();
+            }
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let errors_before = sess.dcx.err_count();
+        let tokens = Lexer::from_source_file(sess, file).into_tokens();
+        if sess.dcx.err_count() == errors_before {
+            let entry = CachedTokens {
+                src: file.src.clone(),
+                start_pos: file.start_pos,
+                tokens: tokens.clone(),
+            };
+            self.entries.lock().insert(file.name.clone(), entry);
+        }
+        tokens
+    }
+
+    /// Removes the cached tokens of the given file, returning `true` if there was an entry.
+    ///
+    /// This should be called when the file is edited.
+    pub fn invalidate(&self, name: &FileName) -> bool {
+        self.entries.lock().remove(name).is_some()
+    }
+
+    /// Removes all cached tokens.
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+
+    /// Returns the number of cached files.
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    /// Returns `true` if no files are cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().is_empty()
+    }
+
+    /// Returns the number of times cached tokens were reused.
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of times a file had to be lexed.
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+impl SourceCache for TokenCache {
+    fn invalidate(&self, name: &FileName) -> bool {
+        self.invalidate(name)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuse_unchanged_files() {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let sm = sess.source_map();
+            let cache = TokenCache::new();
+            let name = FileName::Custom("a".into());
+            let a = sm.new_source_file(name.clone(), || Ok("contract A {}".into())).unwrap();
+            let b = sm.new_source_file(FileName::Custom("b".into()), || Ok("a b".into())).unwrap();
+
+            let tokens = cache.tokens(&sess, &a);
+            assert_eq!(tokens.len(), 4);
+            assert_eq!((cache.hits(), cache.misses()), (0, 1));
+
+            assert_eq!(cache.tokens(&sess, &a), tokens);
+            assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+            cache.tokens(&sess, &b);
+            assert_eq!((cache.hits(), cache.misses()), (1, 2));
+            assert_eq!(cache.len(), 2);
+
+            assert!(cache.invalidate(&name));
+            assert!(!cache.invalidate(&name));
+            assert_eq!(cache.tokens(&sess, &a), tokens);
+            assert_eq!((cache.hits(), cache.misses()), (1, 3));
+
+            // A file with the same name but different contents is lexed again.
+            let edited = SourceFile::new(name, "contract B {}".into(), Default::default()).unwrap();
+            assert_ne!(cache.tokens(&sess, &edited), tokens);
+            assert_eq!((cache.hits(), cache.misses()), (1, 4));
+        });
+    }
+
+    #[test]
+    fn skip_files_with_errors() {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let cache = TokenCache::new();
+            let file = sess
+                .source_map()
+                .new_source_file(FileName::Custom("a".into()), || Ok("\"unterminated".into()))
+                .unwrap();
+            cache.tokens(&sess, &file);
+            cache.tokens(&sess, &file);
+            assert!(cache.is_empty());
+            assert_eq!((cache.hits(), cache.misses()), (0, 2));
+        });
+    }
+}
@@ -0,0 +1,121 @@
+use super::Lexer;
+use solar_ast::token::{Token, TokenKind, TokenLitKind};
+use solar_interface::{source_map::SourceFile, Session, Span};
+
+/// The lexical class of a token, used for syntax highlighting.
+///
+/// See [`classify_tokens`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenClass {
+    /// A keyword, including reserved keywords and the `true` and `false` literals.
+    Keyword,
+    /// An elementary type name, such as `uint256` or `address`.
+    Type,
+    /// A numeric literal.
+    Number,
+    /// A string literal, including unicode and hex strings.
+    String,
+    /// A comment or a doc-comment.
+    Comment,
+    /// An operator.
+    Operator,
+    /// A delimiter or separator, such as `(`, `,` or `;`.
+    Punctuation,
+    /// An identifier that is not a keyword.
+    Identifier,
+}
+
+impl TokenClass {
+    /// Returns the lexical class of the given token.
+    ///
+    /// Classification is purely lexical: identifiers are not resolved, so a user-defined type name
+    /// is classified as an [`Identifier`](Self::Identifier).
+    pub fn of(token: &Token) -> Self {
+        match token.kind {
+            TokenKind::Literal(TokenLitKind::Integer | TokenLitKind::Rational, _) => Self::Number,
+            TokenKind::Literal(..) => Self::String,
+            TokenKind::Comment(..) => Self::Comment,
+            TokenKind::Ident(_) if token.is_elementary_type() => Self::Type,
+            TokenKind::Ident(_) if token.is_reserved_ident(false) => Self::Keyword,
+            TokenKind::Ident(_) => Self::Identifier,
+            TokenKind::OpenDelim(_)
+            | TokenKind::CloseDelim(_)
+            | TokenKind::Dot
+            | TokenKind::Comma
+            | TokenKind::Semi
+            | TokenKind::Colon => Self::Punctuation,
+            _ => Self::Operator,
+        }
+    }
+}
+
+/// Lexes the given source file, returning the span and lexical class of every token, including
+/// comments.
+///
+/// This is intended for syntax highlighting, such as for the LSP `textDocument/semanticTokens`
+/// request. Errors are emitted to the session's diagnostic context as usual.
+///
+/// Note that the source file must be added to the source map before calling this function.
+pub fn classify_tokens(sess: &Session, file: &SourceFile) -> Vec<(Span, TokenClass)> {
+    Lexer::from_source_file(sess, file)
+        .into_tokens()
+        .iter()
+        .map(|token| (token.span, TokenClass::of(token)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solar_interface::source_map::FileName;
+
+    #[test]
+    fn classify() {
+        let src = r#"/// Doc.
+contract C is D {
+    uint256 x = 0x1 + 2.5; // Comment.
+    bool b = true;
+    string s = hex"00";
+}"#;
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let sm = sess.source_map();
+            let file = sm.new_source_file(FileName::Custom("test".into()), || Ok(src.into()));
+            let file = file.unwrap();
+            let classes = classify_tokens(&sess, &file)
+                .into_iter()
+                .map(|(span, class)| (sm.span_to_snippet(span).unwrap(), class))
+                .collect::<Vec<_>>();
+            let expected = [
+                ("/// Doc.", TokenClass::Comment),
+                ("contract", TokenClass::Keyword),
+                ("C", TokenClass::Identifier),
+                ("is", TokenClass::Keyword),
+                ("D", TokenClass::Identifier),
+                ("{", TokenClass::Punctuation),
+                ("uint256", TokenClass::Type),
+                ("x", TokenClass::Identifier),
+                ("=", TokenClass::Operator),
+                ("0x1", TokenClass::Number),
+                ("+", TokenClass::Operator),
+                ("2.5", TokenClass::Number),
+                (";", TokenClass::Punctuation),
+                ("// Comment.", TokenClass::Comment),
+                ("bool", TokenClass::Type),
+                ("b", TokenClass::Identifier),
+                ("=", TokenClass::Operator),
+                ("true", TokenClass::Keyword),
+                (";", TokenClass::Punctuation),
+                ("string", TokenClass::Type),
+                ("s", TokenClass::Identifier),
+                ("=", TokenClass::Operator),
+                ("hex\"00\"", TokenClass::String),
+                (";", TokenClass::Punctuation),
+                ("}", TokenClass::Punctuation),
+            ];
+            let classes = classes.iter().map(|(s, c)| (s.as_str(), *c)).collect::<Vec<_>>();
+            assert_eq!(classes, expected);
+            assert!(sess.dcx.has_errors().is_ok());
+        });
+    }
+}
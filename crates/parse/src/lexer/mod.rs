@@ -8,6 +8,12 @@ use solar_interface::{
     diagnostics::DiagCtxt, source_map::SourceFile, sym, BytePos, Session, Span, Symbol,
 };
 
+mod cache;
+pub use cache::TokenCache;
+
+mod classify;
+pub use classify::{classify_tokens, TokenClass};
+
 mod cursor;
 use cursor::token::{RawLiteralKind, RawToken, RawTokenKind};
 pub use cursor::{is_id_continue, is_id_start, is_ident, is_whitespace, token, Cursor};
@@ -23,6 +29,10 @@ mod utf8;
 /// Converts a [`Cursor`]'s output from simple [`RawTokenKind`]s into rich [`TokenKind`]s, by
 /// converting strings into interned symbols, concatenating tokens together, and running additional
 /// validation.
+///
+/// Tokens can be collected with [`into_tokens`](Self::into_tokens), or consumed lazily through
+/// the [`Iterator`] implementation, in which case only the consumed part of the source is lexed
+/// and validated.
 pub struct Lexer<'sess, 'src> {
     /// The parsing context.
     pub(crate) sess: &'sess Session,
@@ -46,6 +56,9 @@ pub struct Lexer<'sess, 'src> {
     /// in this file, it's safe to treat further occurrences of the non-breaking
     /// space character as whitespace.
     nbsp_is_whitespace: bool,
+
+    /// Whether to emit comment tokens. See [`keep_comments`](Self::keep_comments).
+    keep_comments: bool,
 }
 
 impl<'sess, 'src> Lexer<'sess, 'src> {
@@ -62,7 +75,14 @@ impl<'sess, 'src> Lexer<'sess, 'src> {
     }
 
     /// Creates a new `Lexer` for the given source string and starting position.
+    ///
+    /// A leading UTF-8 byte order mark is skipped. Token spans still point into the original
+    /// source, including the byte order mark.
     pub fn with_start_pos(sess: &'sess Session, src: &'src str, start_pos: BytePos) -> Self {
+        let (src, start_pos) = match src.strip_prefix('\u{feff}') {
+            Some(src) => (src, start_pos + BytePos::from_usize('\u{feff}'.len_utf8())),
+            None => (src, start_pos),
+        };
         let mut lexer = Self {
             sess,
             start_pos,
@@ -71,11 +91,24 @@ impl<'sess, 'src> Lexer<'sess, 'src> {
             cursor: Cursor::new(src),
             token: Token::DUMMY,
             nbsp_is_whitespace: false,
+            keep_comments: true,
         };
         (lexer.token, _) = lexer.bump();
         lexer
     }
 
+    /// Sets whether to emit comment tokens, including doc-comments. Defaults to `true`.
+    ///
+    /// Whitespace is never emitted as tokens, but it can be recovered from the gaps between
+    /// consecutive token spans.
+    ///
+    /// Note that the parser requires doc-comments to be present in the token stream in order to
+    /// attach them to items.
+    pub fn keep_comments(mut self, yes: bool) -> Self {
+        self.keep_comments = yes;
+        self
+    }
+
     /// Returns a reference to the diagnostic context.
     #[inline]
     pub fn dcx(&self) -> &'sess DiagCtxt {
@@ -84,7 +117,7 @@ impl<'sess, 'src> Lexer<'sess, 'src> {
 
     /// Consumes the lexer and collects the remaining tokens into a vector.
     ///
-    /// Note that this skips comments, as [required by the parser](crate::Parser::new).
+    /// Comments are included unless disabled with [`keep_comments`](Self::keep_comments).
     ///
     /// Prefer using this method instead of manually collecting tokens using [`Iterator`].
     #[instrument(name = "lex", level = "debug", skip_all)]
@@ -109,6 +142,15 @@ impl<'sess, 'src> Lexer<'sess, 'src> {
 
     /// Returns the next token, advancing the lexer.
     pub fn next_token(&mut self) -> Token {
+        loop {
+            let token = self.next_token_inner();
+            if self.keep_comments || !token.is_comment_or_doc() {
+                return token;
+            }
+        }
+    }
+
+    fn next_token_inner(&mut self) -> Token {
         let mut next_token;
         loop {
             let preceded_by_whitespace;
@@ -453,6 +495,9 @@ impl<'sess, 'src> Lexer<'sess, 'src> {
     }
 }
 
+/// Yields tokens lazily, lexing at most one token ahead of the last yielded one.
+///
+/// Returns `None` at the end of the input.
 impl Iterator for Lexer<'_, '_> {
     type Item = Token;
 
@@ -531,6 +576,36 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn lazy() {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let src = format!("contract C {{ {} }}", "uint256 x = 1;\n".repeat(100_000));
+            let mut lexer = Lexer::new(&sess, &src);
+            let tokens = lexer.by_ref().take(5).map(|t| t.kind).collect::<Vec<_>>();
+            assert_eq!(
+                tokens,
+                [id("contract"), id("C"), OpenDelim(Delimiter::Brace), id("uint256"), id("x")]
+            );
+            // Only the consumed tokens and a single token of lookahead have been lexed.
+            assert_eq!(lexer.pos.to_usize(), "contract C { uint256 x =".len());
+            assert_eq!(lexer.count(), 100_000 * 5 - 1);
+            sess.dcx.has_errors().unwrap();
+        });
+    }
+
+    #[test]
+    fn bom() {
+        solar_interface::SessionGlobals::new().set(|| {
+            checks(&[
+                ("\u{feff}", &[]),
+                ("\u{feff}a", &[(3..4, id("a"))]),
+                ("\u{feff} a;", &[(4..5, id("a")), (5..6, Semi)]),
+                ("\u{feff}// SPDX\nb", &[(11..12, id("b"))]),
+            ]);
+        });
+    }
+
     #[test]
     fn literals() {
         use TokenLitKind::*;
@@ -620,6 +695,32 @@ mod tests {
         });
     }
 
+    #[test]
+    fn keep_comments() {
+        solar_interface::SessionGlobals::new().set(|| {
+            let sess = Session::builder().with_test_emitter().build();
+            let src = "/// doc\na /* block */ b // line";
+            let lex = |keep| {
+                Lexer::new(&sess, src)
+                    .keep_comments(keep)
+                    .map(|t| (t.span.lo().to_usize()..t.span.hi().to_usize(), t.kind))
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(
+                lex(true),
+                [
+                    (0..7, Comment(true, CommentKind::Line, sym(" doc"))),
+                    (8..9, id("a")),
+                    (10..21, Comment(false, CommentKind::Block, sym(" block "))),
+                    (22..23, id("b")),
+                    (24..31, Comment(false, CommentKind::Line, sym(" line"))),
+                ]
+            );
+            assert_eq!(lex(false), [(8..9, id("a")), (22..23, id("b"))]);
+            sess.dcx.has_errors().unwrap();
+        });
+    }
+
     #[test]
     fn operators() {
         use Delimiter::*;
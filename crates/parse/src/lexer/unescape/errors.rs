@@ -40,6 +40,8 @@ pub enum EscapeError {
     StrNewline,
     /// Non-ASCII character in non-unicode literal.
     StrNonAsciiChar,
+    /// Unescaped control character, such as a tab, in non-unicode literal.
+    StrControlChar,
 
     /// Non hex-digit character in hex literal.
     HexNotHexDigit,
@@ -64,6 +66,7 @@ impl EscapeError {
             Self::InvalidUnicodeEscape => "invalid character in unicode escape",
             Self::StrNewline => "unescaped newline",
             Self::StrNonAsciiChar => "unicode characters are not allowed in string literals; use a `unicode\"...\"` literal instead",
+            Self::StrControlChar => "control characters are not allowed in string literals; use an escape sequence instead",
             Self::HexNotHexDigit => "invalid hex digit",
             Self::HexBadUnderscore => "invalid underscore in hex literal",
             Self::HexOddDigits => "odd number of hex nibbles",
@@ -122,7 +122,7 @@ fn needs_unescape(src: &str, mode: Mode) -> bool {
     }
 
     match mode {
-        Mode::Str => needs_unescape_chars(src) || !src.is_ascii(),
+        Mode::Str => src.bytes().any(|b| b == b'\\' || !(b' '..=b'~').contains(&b)),
         Mode::UnicodeStr => needs_unescape_chars(src),
         Mode::HexStr => src.len() % 2 != 0 || !hex::check_raw(src),
     }
@@ -204,6 +204,8 @@ where
                 Err(EscapeError::BareCarriageReturn)
             }
             c if !is_unicode && !c.is_ascii() => Err(EscapeError::StrNonAsciiChar),
+            // Unicode literals may contain any character, including control characters.
+            c if !is_unicode && c.is_ascii_control() => Err(EscapeError::StrControlChar),
             c => Ok(c as u32),
         };
         let end = src.len() - chars.as_str().len();
@@ -332,8 +334,6 @@ mod tests {
         let cases: &[(&str, &str, &[ExErr])] = &[
             ("", "", &[]),
             (" ", " ", &[]),
-            ("\t", "\t", &[]),
-            (" \t ", " \t ", &[]),
             ("foo", "foo", &[]),
             ("hello world", "hello world", &[]),
             (r"\", "", &[(0..1, LoneSlash)]),
@@ -349,6 +349,7 @@ mod tests {
             (r"\xzf", "f", &[(0..3, InvalidHexEscape)]),
             (r"\xzz", "z", &[(0..3, InvalidHexEscape)]),
             (r"\x69", "\x69", &[]),
+            (r"\x41", "A", &[]),
             (r"\xE8", "è", &[]),
             (r"\u", "", &[(0..2, UnicodeEscapeTooShort)]),
             (r"\u1", "", &[(0..3, UnicodeEscapeTooShort)]),
@@ -357,6 +358,7 @@ mod tests {
             (r"\u12", "", &[(0..4, UnicodeEscapeTooShort)]),
             (r"\u123", "", &[(0..5, UnicodeEscapeTooShort)]),
             (r"\u1234", "\u{1234}", &[]),
+            (r"\u0041", "A", &[]),
             (r"\u00e8", "è", &[]),
             (r"\r", "\r", &[]),
             (r"\t", "\t", &[]),
@@ -364,6 +366,7 @@ mod tests {
             (r"\n\n", "\n\n", &[]),
             (r"\ ", "", &[(0..2, InvalidEscape)]),
             (r"\?", "", &[(0..2, InvalidEscape)]),
+            (r"a\qb", "ab", &[(1..3, InvalidEscape)]),
             ("\r\n", "", &[(1..2, StrNewline)]),
             ("\n", "", &[(0..1, StrNewline)]),
             ("\\\n", "", &[]),
@@ -405,6 +408,18 @@ mod tests {
         let cases: &[(&str, &str, &[ExErr], &[ExErr])] = &[
             ("è", "è", &[], &[(0..2, StrNonAsciiChar)]),
             ("😀", "😀", &[], &[(0..4, StrNonAsciiChar)]),
+            ("🦀🦀", "🦀🦀", &[], &[(0..4, StrNonAsciiChar), (4..8, StrNonAsciiChar)]),
+            // Emoji with a skin tone modifier.
+            ("👍🏽", "👍🏽", &[], &[(0..4, StrNonAsciiChar), (4..8, StrNonAsciiChar)]),
+            // Combining characters are kept as-is, not normalized.
+            ("\u{301}", "\u{301}", &[], &[(0..2, StrNonAsciiChar)]),
+            (
+                "\u{5d1}\u{5bc}",
+                "\u{5d1}\u{5bc}",
+                &[],
+                &[(0..2, StrNonAsciiChar), (2..4, StrNonAsciiChar)],
+            ),
+            ("日本", "日本", &[], &[(0..3, StrNonAsciiChar), (3..6, StrNonAsciiChar)]),
         ];
         for &(src, expected_str, e1, e2) in cases {
             check(Mode::UnicodeStr, src, expected_str, e1);
@@ -412,6 +427,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unescape_control_chars() {
+        let cases: &[(&str, &[ExErr])] = &[
+            ("\t", &[(0..1, StrControlChar)]),
+            (" \t ", &[(1..2, StrControlChar)]),
+            ("a\0b", &[(1..2, StrControlChar)]),
+            ("\x1b[0m", &[(0..1, StrControlChar)]),
+            ("\x7f", &[(0..1, StrControlChar)]),
+        ];
+        for &(src, errs) in cases {
+            check(Mode::UnicodeStr, src, src, &[]);
+            let expected = src.chars().filter(|c| !c.is_ascii_control()).collect::<String>();
+            check(Mode::Str, src, &expected, errs);
+        }
+    }
+
     #[test]
     fn unescape_hex_str() {
         let cases: &[(&str, &str, &[ExErr])] = &[
@@ -11,11 +11,15 @@ extern crate tracing;
 use solar_interface::diagnostics::{DiagBuilder, ErrorGuaranteed};
 
 pub mod lexer;
-pub use lexer::{unescape, Cursor, Lexer};
+pub use lexer::{classify_tokens, unescape, Cursor, Lexer, TokenCache, TokenClass};
 
 mod parser;
 pub use parser::Parser;
 
+pub mod cst;
+
+pub mod fuzz;
+
 // Convenience re-exports.
 pub use bumpalo;
 pub use solar_ast::{self as ast, token};
@@ -14,7 +14,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         &mut self,
         with: Option<Box<'ast, Expr<'ast>>>,
     ) -> PResult<'sess, Box<'ast, Expr<'ast>>> {
-        let expr = self.parse_binary_expr(4, with)?;
+        let expr = self.parse_binary_expr(1, with)?;
         if self.eat(&TokenKind::Question) {
             let then = self.parse_expr()?;
             self.expect(&TokenKind::Colon)?;
@@ -22,6 +22,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             let span = expr.span.to(self.prev_token.span);
             Ok(self.alloc(Expr { span, kind: ExprKind::Ternary(expr, then, else_) }))
         } else {
+            self.maybe_recover_walrus();
             let kind = if let Some(binop_eq) = self.token.as_binop_eq() {
                 Some(binop_eq)
             } else if self.token.kind == TokenKind::Eq {
@@ -36,51 +37,82 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
     }
 
-    /// Parses a binary expression.
+    /// Parses a binary expression whose operators all have a precedence of at least
+    /// `min_precedence`, using precedence climbing over [`BinOpKind::PRECEDENCE`].
     fn parse_binary_expr(
         &mut self,
         min_precedence: usize,
         with: Option<Box<'ast, Expr<'ast>>>,
     ) -> PResult<'sess, Box<'ast, Expr<'ast>>> {
         let mut expr = self.parse_unary_expr(with)?;
-        let mut precedence = token_precedence(&self.token);
-        while precedence >= min_precedence {
-            while token_precedence(&self.token) == precedence {
-                // Parse a**b**c as a**(b**c)
-                let next_precedence = if self.token.kind == TokenKind::BinOp(BinOpToken::Star) {
-                    precedence + 1
-                } else {
-                    precedence
-                };
+        loop {
+            self.maybe_recover_spaced_op();
+            let Some(op) = self.token.as_binop() else { break };
+            let (precedence, assoc) = op.kind.precedence();
+            if precedence < min_precedence {
+                break;
+            }
+            self.bump(); // binop token
 
-                let token = self.token.clone();
-                self.bump(); // binop token
+            let next_precedence = match assoc {
+                Assoc::Left => precedence + 1,
+                Assoc::Right => precedence,
+            };
+            let rhs = self
+                .with_depth("expression", |this| this.parse_binary_expr(next_precedence, None))?;
+
+            let span = expr.span.to(self.prev_token.span);
+            expr = self.alloc(Expr { span, kind: ExprKind::Binary(expr, op, rhs) });
+        }
+        Ok(expr)
+    }
 
-                let rhs = self.parse_binary_expr(next_precedence, None)?;
+    /// Recovers from a shift or compound assignment operator that was written with whitespace in
+    /// it, such as `x >> = 1` or `x > >= 1`, by gluing its tokens back together.
+    fn maybe_recover_spaced_op(&mut self) {
+        let Some(glued) = self.token.glue(self.look_ahead(1)) else { return };
+        if !matches!(
+            glued.kind,
+            TokenKind::BinOp(BinOpToken::Shl | BinOpToken::Shr | BinOpToken::Sar)
+                | TokenKind::BinOpEq(_)
+        ) {
+            return;
+        }
 
-                let span = expr.span.to(self.prev_token.span);
+        let op = glued.kind.as_str();
+        let msg = format!("unexpected whitespace in `{op}`");
+        self.dcx().err(msg).span(glued.span).help(format!("remove the whitespace: `{op}`")).emit();
+        self.bump(); // first half
+        self.token = glued;
+    }
 
-                let kind = if let Some(binop) = token.as_binop() {
-                    ExprKind::Binary(expr, binop, rhs)
-                } else if let Some(binop_eq) = token.as_binop_eq() {
-                    ExprKind::Assign(expr, Some(binop_eq), rhs)
-                } else if token.kind == TokenKind::Eq {
-                    ExprKind::Assign(expr, None, rhs)
-                } else {
-                    let msg = format!("unknown binop token: {token:?}");
-                    self.dcx().bug(msg).span(span).emit();
-                };
-                expr = self.alloc(Expr { span, kind });
-            }
-            precedence -= 1;
+    /// Recovers from `:=` used as an assignment operator outside of inline assembly, by splitting
+    /// off the `:` and treating the rest as `=`.
+    fn maybe_recover_walrus(&mut self) {
+        if self.token.kind != TokenKind::Walrus {
+            return;
         }
-        Ok(expr)
+        let Some((colon, eq)) = self.token.split() else { return };
+        self.dcx()
+            .err("`:=` is only valid in inline assembly")
+            .span(self.token.span)
+            .help("use `=` to assign a value")
+            .emit();
+        self.token = colon;
+        self.bump_with(eq);
     }
 
     /// Parses a unary expression.
     fn parse_unary_expr(
         &mut self,
         with: Option<Box<'ast, Expr<'ast>>>,
+    ) -> PResult<'sess, Box<'ast, Expr<'ast>>> {
+        self.with_depth("expression", |this| this.parse_unary_expr_inner(with))
+    }
+
+    fn parse_unary_expr_inner(
+        &mut self,
+        with: Option<Box<'ast, Expr<'ast>>>,
     ) -> PResult<'sess, Box<'ast, Expr<'ast>>> {
         if with.is_none() && self.eat(&TokenKind::BinOp(BinOpToken::Plus)) {
             self.dcx().err("unary plus is not supported").span(self.prev_token.span).emit();
@@ -219,6 +251,10 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     /// Parses a list of function call arguments.
     #[track_caller]
     pub(super) fn parse_call_args(&mut self) -> PResult<'sess, CallArgs<'ast>> {
+        if let Some(args) = self.maybe_recover_struct_lit_bad_delims()? {
+            return Ok(CallArgs::Named(args));
+        }
+
         if self.look_ahead(1).kind == TokenKind::OpenDelim(Delimiter::Brace) {
             self.expect(&TokenKind::OpenDelim(Delimiter::Parenthesis))?;
             let args = self.parse_named_args().map(CallArgs::Named)?;
@@ -229,6 +265,31 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
     }
 
+    /// Recovers from named arguments that are missing their surrounding braces: `f(a: 1)` instead
+    /// of `f({a: 1})`.
+    #[track_caller]
+    fn maybe_recover_struct_lit_bad_delims(
+        &mut self,
+    ) -> PResult<'sess, Option<NamedArgList<'ast>>> {
+        if !(self.check_noexpect(&TokenKind::OpenDelim(Delimiter::Parenthesis))
+            && self.look_ahead(1).is_ident()
+            && self.look_ahead(2).kind == TokenKind::Colon)
+        {
+            return Ok(None);
+        }
+
+        let lo = self.token.span;
+        let args =
+            self.parse_delim_comma_seq(Delimiter::Parenthesis, false, Self::parse_named_arg)?;
+        let span = lo.to(self.prev_token.span);
+        self.dcx()
+            .err("named arguments must be enclosed in braces")
+            .span(span)
+            .help("use `({name: value, ...})` to pass arguments by name")
+            .emit();
+        Ok(Some(args))
+    }
+
     /// Parses a `[]` indexing expression.
     pub(super) fn parse_expr_index_kind(&mut self) -> PResult<'sess, IndexKind<'ast>> {
         self.expect(&TokenKind::OpenDelim(Delimiter::Bracket))?;
@@ -277,38 +338,6 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     }
 }
 
-fn token_precedence(t: &Token) -> usize {
-    use BinOpToken::*;
-    use TokenKind::*;
-    match t.kind {
-        Question => 3,
-        BinOpEq(_) => 2,
-        Comma => 1,
-        OrOr => 4,
-        AndAnd => 5,
-        BinOp(Or) => 8,
-        BinOp(Caret) => 9,
-        BinOp(And) => 10,
-        BinOp(Shl) => 11,
-        BinOp(Sar) => 11,
-        BinOp(Shr) => 11,
-        BinOp(Plus) => 12,
-        BinOp(Minus) => 12,
-        BinOp(Star) => 13,
-        BinOp(Slash) => 13,
-        BinOp(Percent) => 13,
-        StarStar => 4,
-        EqEq => 6,
-        Ne => 6,
-        Lt => 7,
-        Gt => 7,
-        Le => 7,
-        Ge => 7,
-        Walrus => 2,
-        _ => 0,
-    }
-}
-
 /// Converts a list of `Option<Box<'ast, T>>` into a list of `Box<'ast, T>`.
 ///
 /// This only works because `Option<Box<'ast, T>>` is guaranteed to be a valid `Box<'ast, T>` when
@@ -325,3 +354,294 @@ unsafe fn option_boxes_unwrap_unchecked<'a, 'b, T>(
     // SAFETY: Caller must ensure that all elements are `Some`.
     unsafe { std::mem::transmute(list) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solar_interface::{source_map::FileName, Result, Session};
+
+    /// Parses `src` as an expression, asserting that no errors were emitted.
+    #[track_caller]
+    fn with_expr(src: &str, f: impl FnOnce(&Expr<'_>)) {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| -> Result {
+            let arena = Arena::new();
+            let filename = FileName::Custom("test".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, filename, src.into())?;
+            let expr = parser.parse_expr().map_err(|e| e.emit())?;
+            sess.dcx.has_errors()?;
+            f(expr);
+            Ok(())
+        })
+        .unwrap_or_else(|_| panic!("src: {src:?}"));
+    }
+
+    /// Renders binary expressions with explicit parentheses.
+    fn group(expr: &Expr<'_>) -> String {
+        match &expr.kind {
+            ExprKind::Binary(lhs, op, rhs) => format!("({} {op} {})", group(lhs), group(rhs)),
+            _ => solar_ast::print::expr_to_string(expr),
+        }
+    }
+
+    #[track_caller]
+    fn assert_grouping(cases: &[(&str, &str)]) {
+        for &(src, expected) in cases {
+            with_expr(src, |expr| assert_eq!(group(expr), expected, "{src:?}"));
+        }
+    }
+
+    #[test]
+    fn binary_precedence() {
+        let cases = [
+            ("a / b * c", "((a / b) * c)"),
+            ("a ** b ** c", "(a ** (b ** c))"),
+            ("a * b ** c", "(a * (b ** c))"),
+            ("a + b * c", "(a + (b * c))"),
+            ("a << b + c", "(a << (b + c))"),
+            ("a & b << c", "(a & (b << c))"),
+            ("a ^ b & c", "(a ^ (b & c))"),
+            ("a | b ^ c", "(a | (b ^ c))"),
+            ("a < b | c", "(a < (b | c))"),
+            ("a == b < c", "(a == (b < c))"),
+            ("a && b == c", "(a && (b == c))"),
+            ("a || b && c", "(a || (b && c))"),
+            ("a || b && c == d + e ** f", "(a || (b && (c == (d + (e ** f)))))"),
+        ];
+        assert_grouping(&cases);
+    }
+
+    // The following used to be parsed incorrectly before the precedence table was introduced.
+
+    #[test]
+    fn binary_left_associative() {
+        // Every operator except `*` was parsed as right-associative.
+        assert_grouping(&[
+            ("a - b - c", "((a - b) - c)"),
+            ("a / b / c", "((a / b) / c)"),
+            ("a % b * c", "((a % b) * c)"),
+            ("a << b >> c", "((a << b) >> c)"),
+            ("a == b != c", "((a == b) != c)"),
+            ("a < b > c", "((a < b) > c)"),
+        ]);
+    }
+
+    #[test]
+    fn pow_binds_tightest() {
+        // `**` had the same precedence as `||`, the lowest one.
+        assert_grouping(&[
+            ("a + b ** c", "(a + (b ** c))"),
+            ("a ** b + c", "((a ** b) + c)"),
+            ("a == b ** c", "(a == (b ** c))"),
+            ("a || b ** c", "(a || (b ** c))"),
+        ]);
+    }
+
+    #[test]
+    fn caret_is_bitxor() {
+        // `^` used to be parsed as `**`.
+        with_expr("a ^ b", |expr| {
+            let ExprKind::Binary(_, op, _) = &expr.kind else { panic!("{expr:?}") };
+            assert_eq!(op.kind, BinOpKind::BitXor);
+        });
+        with_expr("a ^= b", |expr| {
+            let ExprKind::Assign(_, Some(op), _) = &expr.kind else { panic!("{expr:?}") };
+            assert_eq!(op.kind, BinOpKind::BitXor);
+        });
+    }
+
+    #[test]
+    fn assign_is_not_equality() {
+        // `=` was mapped to `BinOpKind::Eq`.
+        assert_eq!(TokenKind::Eq.as_binop(), None);
+        with_expr("a = b == c", |expr| {
+            let ExprKind::Assign(_, None, rhs) = &expr.kind else { panic!("{expr:?}") };
+            assert!(
+                matches!(rhs.kind, ExprKind::Binary(_, BinOp { kind: BinOpKind::Eq, .. }, _)),
+                "{rhs:?}"
+            );
+        });
+    }
+
+    #[test]
+    fn delete() {
+        with_expr("delete balances[msg.sender]", |expr| {
+            let ExprKind::Delete(inner) = &expr.kind else { panic!("{expr:?}") };
+            assert!(matches!(inner.kind, ExprKind::Index(..)));
+        });
+        with_expr("delete arr[i][j]", |expr| {
+            let ExprKind::Delete(inner) = &expr.kind else { panic!("{expr:?}") };
+            let ExprKind::Index(base, _) = &inner.kind else { panic!("{inner:?}") };
+            assert!(matches!(base.kind, ExprKind::Index(..)));
+        });
+        with_expr("delete myStruct", |expr| {
+            let ExprKind::Delete(inner) = &expr.kind else { panic!("{expr:?}") };
+            assert!(matches!(inner.kind, ExprKind::Ident(..)));
+        });
+        with_expr("delete s.field", |expr| {
+            let ExprKind::Delete(inner) = &expr.kind else { panic!("{expr:?}") };
+            assert!(matches!(inner.kind, ExprKind::Member(..)));
+        });
+        // `delete` binds tighter than binary operators.
+        with_expr("delete x + 1", |expr| {
+            let ExprKind::Binary(lhs, op, _) = &expr.kind else { panic!("{expr:?}") };
+            assert_eq!(op.kind, BinOpKind::Add);
+            assert!(matches!(lhs.kind, ExprKind::Delete(..)));
+        });
+    }
+
+    #[test]
+    fn call_args() {
+        with_expr("f()", |expr| {
+            let ExprKind::Call(_, args) = &expr.kind else { panic!("{expr:?}") };
+            assert!(matches!(args, CallArgs::Unnamed(args) if args.is_empty()));
+        });
+        with_expr("f(1, 2)", |expr| {
+            let ExprKind::Call(_, args) = &expr.kind else { panic!("{expr:?}") };
+            assert!(matches!(args, CallArgs::Unnamed(args) if args.len() == 2));
+        });
+        with_expr("f({x: 1, y: 2})", |expr| {
+            let ExprKind::Call(_, CallArgs::Named(args)) = &expr.kind else { panic!("{expr:?}") };
+            let names = args.iter().map(|arg| arg.name.as_str()).collect::<Vec<_>>();
+            assert_eq!(names, ["x", "y"]);
+        });
+        // Call options come before the parenthesized arguments.
+        with_expr("c.call{value: 1, gas: 2}(data)", |expr| {
+            let ExprKind::Call(callee, CallArgs::Unnamed(args)) = &expr.kind else {
+                panic!("{expr:?}")
+            };
+            assert_eq!(args.len(), 1);
+            let ExprKind::CallOptions(_, options) = &callee.kind else { panic!("{callee:?}") };
+            assert_eq!(options.len(), 2);
+        });
+        with_expr("c.f{value: 1}({x: 1})", |expr| {
+            let ExprKind::Call(callee, CallArgs::Named(args)) = &expr.kind else {
+                panic!("{expr:?}")
+            };
+            assert_eq!(args.len(), 1);
+            assert!(matches!(callee.kind, ExprKind::CallOptions(..)));
+        });
+    }
+
+    #[test]
+    fn array_mutators() {
+        #[track_caller]
+        fn check(src: &str, expected_member: &str, expected_args: usize) {
+            with_expr(src, |expr| {
+                let ExprKind::Call(callee, CallArgs::Unnamed(args)) = &expr.kind else {
+                    panic!("{expr:?}")
+                };
+                assert_eq!(args.len(), expected_args);
+                let ExprKind::Member(base, member) = &callee.kind else { panic!("{callee:?}") };
+                assert!(matches!(base.kind, ExprKind::Ident(..)));
+                assert_eq!(member.as_str(), expected_member);
+                assert!(is_array_mutator(member.name));
+            });
+        }
+        check("a.push()", "push", 0);
+        check("a.push(1)", "push", 1);
+        check("a.pop()", "pop", 0);
+    }
+
+    #[test]
+    fn call_options() {
+        #[track_caller]
+        fn split_options<'a, 'ast>(expr: &'a Expr<'ast>) -> (&'a Expr<'ast>, Vec<&'a str>) {
+            let ExprKind::Call(callee, _) = &expr.kind else { panic!("{expr:?}") };
+            let ExprKind::CallOptions(callee, options) = &callee.kind else { panic!("{callee:?}") };
+            (callee, options.iter().map(|arg| arg.name.as_str()).collect())
+        }
+
+        with_expr("addr.call{value: 1 ether}(\"\")", |expr| {
+            let (callee, options) = split_options(expr);
+            assert!(matches!(callee.kind, ExprKind::Member(..)));
+            assert_eq!(options, ["value"]);
+        });
+        with_expr("new C{salt: s, value: v}(a)", |expr| {
+            let (callee, options) = split_options(expr);
+            assert!(matches!(callee.kind, ExprKind::New(..)));
+            assert_eq!(options, ["salt", "value"]);
+        });
+        with_expr("x.f{gas: 1}().g{gas: 2}()", |expr| {
+            let (callee, options) = split_options(expr);
+            assert_eq!(options, ["gas"]);
+            let ExprKind::Member(inner, _) = &callee.kind else { panic!("{callee:?}") };
+            assert_eq!(split_options(inner).1, ["gas"]);
+        });
+        // Unknown options are preserved and left to semantic analysis.
+        with_expr("f{foo: 1}()", |expr| {
+            assert_eq!(split_options(expr).1, ["foo"]);
+        });
+    }
+
+    #[test]
+    fn new_with_salt() {
+        #[track_caller]
+        fn new_ty(expr: &Expr<'_>) -> String {
+            let ExprKind::New(ty) = &expr.kind else { panic!("{expr:?}") };
+            solar_ast::print::ty_to_string(ty)
+        }
+
+        with_expr("new Token{salt: keccak256(x)}(\"n\", \"s\")", |expr| {
+            let ExprKind::Call(callee, args) = &expr.kind else { panic!("{expr:?}") };
+            assert_eq!(args.len(), 2);
+            let ExprKind::CallOptions(new, options) = &callee.kind else { panic!("{callee:?}") };
+            assert_eq!(new_ty(new), "Token");
+            let [salt] = &options[..] else { panic!("{options:?}") };
+            assert_eq!(salt.name.as_str(), "salt");
+            assert_eq!(solar_ast::print::expr_to_string(salt.value), "keccak256(x)");
+        });
+        with_expr("new Token(\"n\", \"s\")", |expr| {
+            let ExprKind::Call(callee, args) = &expr.kind else { panic!("{expr:?}") };
+            assert_eq!(args.len(), 2);
+            assert_eq!(new_ty(callee), "Token");
+        });
+    }
+
+    #[test]
+    fn type_call() {
+        #[track_caller]
+        fn check(src: &str, expected_ty: &str, expected_member: &str) {
+            with_expr(src, |expr| {
+                let ExprKind::Member(base, member) = &expr.kind else { panic!("{expr:?}") };
+                let ExprKind::TypeCall(ty) = &base.kind else { panic!("{base:?}") };
+                assert_eq!(solar_ast::print::ty_to_string(ty), expected_ty);
+                assert_eq!(member.as_str(), expected_member);
+            });
+        }
+
+        check("type(uint8).max", "uint8", "max");
+        check("type(int256).min", "int256", "min");
+        check("type(MyContract).creationCode", "MyContract", "creationCode");
+        check("type(a.I).interfaceId", "a.I", "interfaceId");
+        check("type(C).name", "C", "name");
+    }
+
+    #[test]
+    fn payable_conversion() {
+        with_expr("payable(msg.sender)", |expr| {
+            let ExprKind::Payable(args) = &expr.kind else { panic!("{expr:?}") };
+            assert_eq!(args.len(), 1);
+        });
+        with_expr("payable(a).transfer(1)", |expr| {
+            let ExprKind::Call(callee, _) = &expr.kind else { panic!("{expr:?}") };
+            let ExprKind::Member(base, _) = &callee.kind else { panic!("{callee:?}") };
+            assert!(matches!(base.kind, ExprKind::Payable(_)));
+        });
+    }
+
+    #[test]
+    fn nested_too_deeply() {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let arena = Arena::new();
+            let n = 10_000;
+            let src = format!("{}1{}", "(".repeat(n), ")".repeat(n));
+            let filename = FileName::Custom("test".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, filename, src).unwrap();
+            let err = parser.parse_expr().unwrap_err();
+            assert_eq!(err.label(), "expression nested too deeply");
+            err.cancel();
+        });
+    }
+}
@@ -8,7 +8,9 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     /// Parses a source unit.
     #[instrument(level = "debug", skip_all)]
     pub fn parse_file(&mut self) -> PResult<'sess, SourceUnit<'ast>> {
-        self.parse_items(&TokenKind::Eof).map(SourceUnit::new)
+        let items = self.parse_items(&TokenKind::Eof)?;
+        let licenses = std::mem::take(&mut self.licenses);
+        Ok(SourceUnit::new(items, self.alloc_vec(licenses)))
     }
 
     /// Parses a list of items until the given token is encountered.
@@ -31,7 +33,25 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         };
 
         let mut items = Vec::new();
-        while let Some(item) = self.parse_item()? {
+        loop {
+            if self.in_contract && self.is_contract_like() {
+                break;
+            }
+            let lo = self.token.span;
+            let item = match self.parse_item() {
+                Ok(Some(item)) => item,
+                Ok(None) => break,
+                // Recover at the next global item so that errors in later items are reported too.
+                Err(e) if !self.in_contract => {
+                    e.emit();
+                    if self.token.span == lo {
+                        self.bump();
+                    }
+                    self.recover_to_next_global_item();
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
             if self.in_contract && !item.is_allowed_in_contract() {
                 let msg = format!("{}s are not allowed in contracts", item.description());
                 let (_, note) = get_msg_note(self);
@@ -40,6 +60,15 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 items.push(item);
             }
         }
+        if self.in_contract && (self.token.is_eof() || self.is_contract_like()) {
+            let err = self.unclosed_block_err();
+            if self.token.is_eof() {
+                return Err(err);
+            }
+            // The closing brace is likely missing; end the contract here and parse the next one.
+            err.emit();
+            return Ok(self.alloc_vec(items));
+        }
         if !self.eat(end) {
             let (msg, note) = get_msg_note(self);
             return Err(self.dcx().err(msg).span(self.token.span).note(note));
@@ -47,6 +76,41 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         Ok(self.alloc_vec(items))
     }
 
+    /// Skips tokens until the start of the next global item, or after the next `;` or `}` that is
+    /// not nested in braces.
+    fn recover_to_next_global_item(&mut self) {
+        loop {
+            if self.token.is_eof() {
+                return;
+            }
+            if self.open_braces.is_empty() {
+                if self.is_function_like()
+                    || self.is_contract_like()
+                    || self.peek_keyword_any(&[
+                        kw::Struct,
+                        kw::Event,
+                        kw::Enum,
+                        kw::Type,
+                        kw::Pragma,
+                        kw::Import,
+                        kw::Using,
+                    ])
+                {
+                    return;
+                }
+                if self.token.kind == TokenKind::Semi {
+                    self.bump();
+                    return;
+                }
+            }
+            let is_close_brace = self.token.kind == TokenKind::CloseDelim(Delimiter::Brace);
+            self.bump();
+            if is_close_brace && self.open_braces.is_empty() {
+                return;
+            }
+        }
+    }
+
     /// Parses an item.
     #[instrument(level = "debug", skip_all)]
     pub fn parse_item(&mut self) -> PResult<'sess, Option<Item<'ast>>> {
@@ -74,7 +138,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             self.parse_import().map(ItemKind::Import)
         } else if self.eat_keyword(kw::Using) {
             self.parse_using().map(ItemKind::Using)
-        } else if self.check_keyword(sym::error)
+        } else if self.peek_keyword(sym::error)
             && self.look_ahead(1).is_ident()
             && self.look_ahead(2).is_open_delim(Delimiter::Parenthesis)
         {
@@ -91,30 +155,42 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
 
     /// Returns `true` if the current token is the start of a function definition.
     fn is_function_like(&self) -> bool {
-        (self.token.is_keyword(kw::Function)
+        (self.peek_keyword(kw::Function)
             && !self.look_ahead(1).is_open_delim(Delimiter::Parenthesis))
-            || self.token.is_keyword_any(&[
+            || self.peek_keyword_any(&[kw::Constructor, kw::Fallback, kw::Receive, kw::Modifier])
+    }
+
+    /// Returns `true` if the current token can only be the start of an item, and not of a
+    /// statement.
+    pub(super) fn is_item_start(&self) -> bool {
+        self.is_contract_like()
+            || (self.peek_keyword(kw::Function) && self.look_ahead(1).is_ident())
+            || self.peek_keyword_any(&[
                 kw::Constructor,
-                kw::Fallback,
-                kw::Receive,
                 kw::Modifier,
+                kw::Struct,
+                kw::Enum,
+                kw::Event,
+                kw::Pragma,
+                kw::Import,
+                kw::Using,
             ])
     }
 
     /// Returns `true` if the current token is the start of a contract definition.
     fn is_contract_like(&self) -> bool {
-        self.token.is_keyword_any(&[kw::Abstract, kw::Contract, kw::Interface, kw::Library])
+        self.peek_keyword_any(&[kw::Abstract, kw::Contract, kw::Interface, kw::Library])
     }
 
     /// Returns `true` if the current token is the start of a variable declaration.
     pub(super) fn is_variable_declaration(&self) -> bool {
         // https://github.com/ethereum/solidity/blob/194b114664c7daebc2ff68af3c573272f5d28913/libsolidity/parsing/Parser.cpp#L2451
-        self.token.is_non_reserved_ident(false) || self.is_non_custom_variable_declaration()
+        self.is_nr_ident(&self.token) || self.is_non_custom_variable_declaration()
     }
 
     pub(super) fn is_non_custom_variable_declaration(&self) -> bool {
-        self.token.is_keyword(kw::Mapping)
-            || (self.token.is_keyword(kw::Function)
+        self.peek_keyword(kw::Mapping)
+            || (self.peek_keyword(kw::Function)
                 && self.look_ahead(1).is_open_delim(Delimiter::Parenthesis))
             || self.token.is_elementary_type()
     }
@@ -174,7 +250,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             // Allow and warn on `function fallback` or `function receive`.
             let ident;
             if flags == FunctionFlags::FUNCTION
-                && self.token.is_keyword_any(&[kw::Fallback, kw::Receive])
+                && self.peek_keyword_any(&[kw::Fallback, kw::Receive])
             {
                 let kw_span = self.prev_token.span;
                 ident = self.parse_ident_any()?;
@@ -189,7 +265,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 ident = self.parse_ident()?;
             }
             header.name = Some(ident);
-        } else if self.token.is_non_reserved_ident(false) {
+        } else if self.is_nr_ident(&self.token) {
             let msg = "function names are not allowed here";
             self.dcx().err(msg).span(self.token.span).emit();
             self.bump();
@@ -204,6 +280,9 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
 
         let mut modifiers = Vec::new();
+        let mut visibility_span = None;
+        let mut state_mutability_span = None;
+        let mut virtual_span = None;
         loop {
             // This is needed to skip parsing surrounding variable's visibility in function types.
             // E.g. in `function(uint) external internal e;` the `internal` is the surrounding
@@ -216,46 +295,54 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 if !flags.contains(FunctionFlags::from_visibility(visibility)) {
                     let msg = visibility_error(visibility, flags.visibilities());
                     self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if header.visibility.is_some() {
-                    let msg = "visibility already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                } else if let Some(prev) = visibility_span {
+                    self.already_specified("visibility", prev);
                 } else {
                     header.visibility = Some(visibility);
+                    visibility_span = Some(self.prev_token.span);
                 }
             } else if let Some(state_mutability) = self.parse_state_mutability() {
                 if !flags.contains(FunctionFlags::from_state_mutability(state_mutability)) {
                     let msg = state_mutability_error(state_mutability, flags.state_mutabilities());
                     self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if !header.state_mutability.is_non_payable() {
-                    let msg = "state mutability already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                } else if let Some(prev_span) = state_mutability_span {
+                    let prev = header.state_mutability;
+                    let msg = if prev == state_mutability {
+                        format!("`{state_mutability}` already specified")
+                    } else {
+                        format!("state mutability already specified as `{prev}`")
+                    };
+                    self.dcx()
+                        .err(msg)
+                        .code(error_code!(9680))
+                        .span(self.prev_token.span)
+                        .span_label(prev_span, "previously specified here")
+                        .emit();
                 } else {
                     header.state_mutability = state_mutability;
+                    state_mutability_span = Some(self.prev_token.span);
                 }
             } else if self.eat_keyword(kw::Virtual) {
                 if !flags.contains(FunctionFlags::VIRTUAL) {
                     let msg = "`virtual` is not allowed here";
                     self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if header.virtual_ {
-                    let msg = "virtual already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                } else if let Some(prev) = virtual_span {
+                    self.already_specified("virtual", prev);
                 } else {
                     header.virtual_ = true;
+                    virtual_span = Some(self.prev_token.span);
                 }
             } else if self.eat_keyword(kw::Override) {
                 let o = self.parse_override()?;
                 if !flags.contains(FunctionFlags::OVERRIDE) {
                     let msg = "`override` is not allowed here";
                     self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if header.override_.is_some() {
-                    let msg = "override already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                } else if let Some(prev) = &header.override_ {
+                    self.already_specified("override", prev.span);
                 } else {
                     header.override_ = Some(o);
                 }
-            } else if flags.contains(FunctionFlags::MODIFIERS)
-                && self.token.is_non_reserved_ident(false)
-            {
+            } else if flags.contains(FunctionFlags::MODIFIERS) && self.is_nr_ident(&self.token) {
                 modifiers.push(self.parse_modifier()?);
             } else {
                 break;
@@ -278,11 +365,28 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             Delimiter::Brace,
             SeqSep::trailing_enforced(TokenKind::Semi),
             true,
-            |this| this.parse_variable_definition(VarFlags::STRUCT),
+            Self::parse_struct_field,
         )?;
         Ok(ItemStruct { name, fields })
     }
 
+    /// Parses a struct field, recovering from an initializer.
+    fn parse_struct_field(&mut self) -> PResult<'sess, VariableDefinition<'ast>> {
+        let field = self.parse_variable_definition(VarFlags::STRUCT)?;
+        // Don't use `eat` to avoid suggesting `=` in the expected tokens.
+        if self.token.kind == TokenKind::Eq {
+            let lo = self.token.span;
+            self.bump();
+            let expr = self.parse_expr()?;
+            self.dcx()
+                .err("struct fields cannot have initializers")
+                .span(lo.to(expr.span))
+                .help("remove the initializer")
+                .emit();
+        }
+        Ok(field)
+    }
+
     /// Parses an event definition.
     fn parse_event(&mut self) -> PResult<'sess, ItemEvent<'ast>> {
         let name = self.parse_ident()?;
@@ -348,9 +452,8 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     fn parse_pragma(&mut self) -> PResult<'sess, PragmaDirective<'ast>> {
         let is_ident_or_strlit = |t: &Token| t.is_ident() || t.is_str_lit();
 
-        let tokens = if self.check_keyword(sym::solidity)
-            || (self.token.is_ident()
-                && self.look_ahead_with(1, |t| t.is_op() || t.is_rational_lit()))
+        let tokens = if self.peek_keyword(sym::solidity)
+            || (self.peek_ident() && self.look_ahead_with(1, |t| t.is_op() || t.is_rational_lit()))
         {
             // `pragma <ident> <req>;`
             let ident = self.parse_ident_any()?;
@@ -364,11 +467,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             // `pragma <k>;`
             // `pragma <k> <v>;`
             let k = self.parse_ident_or_strlit()?;
-            let v = if self.token.is_ident() || self.token.is_str_lit() {
-                Some(self.parse_ident_or_strlit()?)
-            } else {
-                None
-            };
+            let v = self.parse_opt(Self::parse_ident_or_strlit)?;
             PragmaTokens::Custom(k, v)
         } else {
             let mut tokens = Vec::new();
@@ -659,49 +758,53 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         let mut data_location = None;
         let mut visibility = None;
         let mut mutability = None;
-        let mut override_ = None;
+        let mut override_: Option<Override<'_>> = None;
         let mut indexed = false;
+        let mut data_location_span = None;
+        let mut visibility_span = None;
+        let mut mutability_span = None;
+        let mut indexed_span = None;
         loop {
             if let Some(s) = self.parse_data_location() {
                 if !flags.contains(VarFlags::DATALOC) {
                     let msg = "data locations are not allowed here";
                     self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if data_location.is_some() {
-                    let msg = "data location already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                } else if let Some(prev) = data_location_span {
+                    self.already_specified("data location", prev);
                 } else {
                     data_location = Some(s);
+                    data_location_span = Some(self.prev_token.span);
                 }
             } else if let Some(v) = self.parse_visibility() {
                 if !flags.contains(VarFlags::from_visibility(v)) {
                     let msg = visibility_error(v, flags.visibilities());
                     self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if visibility.is_some() {
-                    let msg = "visibility already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                } else if let Some(prev) = visibility_span {
+                    self.already_specified("visibility", prev);
                 } else {
                     visibility = Some(v);
+                    visibility_span = Some(self.prev_token.span);
                 }
             } else if let Some(m) = self.parse_variable_mutability() {
                 // `CONSTANT_VAR` is special cased later.
                 if flags != VarFlags::CONSTANT_VAR && !flags.contains(VarFlags::from_varmut(m)) {
                     let msg = varmut_error(m, flags.varmuts());
                     self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if mutability.is_some() {
-                    let msg = "mutability already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                } else if let Some(prev) = mutability_span {
+                    self.already_specified("mutability", prev);
                 } else {
                     mutability = Some(m);
+                    mutability_span = Some(self.prev_token.span);
                 }
             } else if self.eat_keyword(kw::Indexed) {
                 if !flags.contains(VarFlags::INDEXED) {
                     let msg = "`indexed` is not allowed here";
                     self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if indexed {
-                    let msg = "`indexed` already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                } else if let Some(prev) = indexed_span {
+                    self.already_specified("`indexed`", prev);
                 } else {
                     indexed = true;
+                    indexed_span = Some(self.prev_token.span);
                 }
             } else if self.eat_keyword(kw::Virtual) {
                 let msg = "`virtual` is not allowed here";
@@ -711,9 +814,8 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 if !flags.contains(VarFlags::OVERRIDE) {
                     let msg = "`override` is not allowed here";
                     self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if override_.is_some() {
-                    let msg = "override already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                } else if let Some(prev) = &override_ {
+                    self.already_specified("override", prev.span);
                 } else {
                     override_ = Some(o);
                 }
@@ -791,13 +893,12 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
 
     /// Parses a list of inheritance specifiers.
     fn parse_inheritance(&mut self) -> PResult<'sess, Box<'ast, [Modifier<'ast>]>> {
-        self.parse_seq_to_before_end(
-            &TokenKind::OpenDelim(Delimiter::Brace),
-            SeqSep::trailing_disallowed(TokenKind::Comma),
+        self.parse_separated_until(
+            TokenKind::Comma,
+            &[&TokenKind::OpenDelim(Delimiter::Brace)],
             false,
             Self::parse_modifier,
         )
-        .map(|(x, _)| x)
     }
 
     /// Parses a single modifier invocation.
@@ -826,6 +927,15 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         Ok(Override { span, paths })
     }
 
+    /// Emits an error for a specifier that was already specified at `prev`.
+    fn already_specified(&mut self, what: &str, prev: Span) {
+        self.dcx()
+            .err(format!("{what} already specified"))
+            .span(self.prev_token.span)
+            .span_label(prev, "previously specified here")
+            .emit();
+    }
+
     /// Parses a single string literal. This is only used in import paths and statements, not
     /// expressions.
     pub(super) fn parse_str_lit(&mut self) -> PResult<'sess, StrLit> {
@@ -856,7 +966,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             Some(DataLocation::Memory)
         } else if self.eat_keyword(kw::Calldata) {
             Some(DataLocation::Calldata)
-        } else if self.check_keyword(sym::transient)
+        } else if self.peek_keyword(sym::transient)
             && !matches!(
                 self.look_ahead(1).kind,
                 TokenKind::Eq | TokenKind::Semi | TokenKind::CloseDelim(_) | TokenKind::Comma
@@ -1304,6 +1414,22 @@ mod tests {
     use super::*;
     use solar_interface::{source_map::FileName, Result, Session};
 
+    /// Parses `src` as a source file, asserting that no errors were emitted.
+    #[track_caller]
+    fn with_source_unit(src: &str, f: impl FnOnce(&SourceUnit<'_>)) {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| -> Result {
+            let arena = Arena::new();
+            let filename = FileName::Custom("test".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, filename, src.into())?;
+            let unit = parser.parse_file().map_err(|e| e.emit())?;
+            sess.dcx.has_errors()?;
+            f(&unit);
+            Ok(())
+        })
+        .unwrap();
+    }
+
     fn assert_version_matches(tests: &[(&str, &str, bool)]) {
         let sess = Session::builder().with_test_emitter().build();
         sess.enter(|| -> Result {
@@ -1499,4 +1625,815 @@ mod tests {
             ("0.8.1", "0.8 || 0.9", true),
         ]);
     }
+
+    #[test]
+    fn inheritance_specifiers() {
+        let src = r#"contract C is Ownable, ERC20("Tok", "TK"), a.B() {}"#;
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items.raw[0].kind else { panic!() };
+            let bases = contract
+                .bases
+                .iter()
+                .map(|base| (base.name.to_string(), base.arguments.len()))
+                .collect::<Vec<_>>();
+            assert_eq!(bases, [("Ownable".into(), 0), ("ERC20".into(), 2), ("a.B".into(), 0)]);
+        });
+    }
+
+    #[test]
+    fn spdx_licenses() {
+        #[track_caller]
+        fn check(src: &str, expected: &[&str]) {
+            with_source_unit(src, |unit| {
+                let licenses = unit.licenses.iter().map(|l| l.value.as_str()).collect::<Vec<_>>();
+                assert_eq!(licenses, expected, "{src:?}");
+            });
+        }
+
+        check("// SPDX-License-Identifier: MIT\npragma solidity ^0.8.0;", &["MIT"]);
+        check(
+            "/* SPDX-License-Identifier: GPL-2.0-or-later OR MIT */ contract C {}",
+            &["GPL-2.0-or-later OR MIT"],
+        );
+        check(
+            "contract C {} /// SPDX-License-Identifier: (MIT AND Apache-2.0)",
+            &["(MIT AND Apache-2.0)"],
+        );
+        check(
+            "// SPDX-License-Identifier: MIT\n// SPDX-License-Identifier: UNLICENSED",
+            &["MIT", "UNLICENSED"],
+        );
+        check("// License: MIT\n// SPDX-License-Identifier:\ncontract C {}", &[]);
+    }
+
+    #[test]
+    fn udvt_and_inheritance() {
+        let src = "type T is uint256; contract C is T {} type U is address payable;
+            abstract contract D is C, T { type V is bool; }";
+        with_source_unit(src, |unit| {
+            let udvt = |item: &Item<'_>| {
+                let ItemKind::Udvt(udvt) = &item.kind else { panic!("{item:#?}") };
+                (udvt.name.to_string(), solar_ast::print::ty_to_string(&udvt.ty))
+            };
+            let bases = |item: &Item<'_>| {
+                let ItemKind::Contract(contract) = &item.kind else { panic!("{item:#?}") };
+                contract.bases.iter().map(|base| base.name.to_string()).collect::<Vec<_>>()
+            };
+            let [t, c, u, d] = &unit.items.raw[..] else { panic!("{unit:#?}") };
+            assert_eq!(udvt(t), ("T".into(), "uint256".into()));
+            assert_eq!(bases(c), ["T"]);
+            assert_eq!(udvt(u), ("U".into(), "address payable".into()));
+            assert_eq!(bases(d), ["C", "T"]);
+            let ItemKind::Contract(contract) = &d.kind else { unreachable!() };
+            assert_eq!(udvt(&contract.body[0]), ("V".into(), "bool".into()));
+        });
+    }
+
+    #[test]
+    fn override_specifiers() {
+        let src = "contract C {
+            uint256 public override x;
+            function a() public override {}
+            function b() public virtual override(A) {}
+            function c() public override(A, b.B) virtual {}
+            function d() public {}
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items.raw[0].kind else { panic!() };
+            let overrides = contract
+                .body
+                .iter()
+                .map(|item| {
+                    let (override_, virtual_) = match &item.kind {
+                        ItemKind::Variable(var) => (&var.override_, false),
+                        ItemKind::Function(f) => (&f.header.override_, f.header.virtual_),
+                        _ => unreachable!(),
+                    };
+                    let paths = override_
+                        .as_ref()
+                        .map(|o| o.paths.iter().map(ToString::to_string).collect::<Vec<_>>());
+                    (paths, virtual_)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                overrides,
+                [
+                    (Some(vec![]), false),
+                    (Some(vec![]), false),
+                    (Some(vec!["A".into()]), true),
+                    (Some(vec!["A".into(), "b.B".into()]), true),
+                    (None, false),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn virtual_specifiers() {
+        let src = "contract C {
+            function a() public virtual override {}
+            function b() override virtual public {}
+            function c() virtual external view {}
+            function d() public {}
+            modifier m() virtual { _; }
+            modifier n() { _; }
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items.raw[0].kind else { panic!() };
+            let specifiers = contract
+                .body
+                .iter()
+                .map(|item| {
+                    let ItemKind::Function(f) = &item.kind else { panic!("{item:#?}") };
+                    let visibility = f.header.visibility.map(|v| v.to_str());
+                    (f.header.virtual_, visibility, f.header.override_.is_some())
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                specifiers,
+                [
+                    (true, Some("public"), true),
+                    (true, Some("public"), true),
+                    (true, Some("external"), false),
+                    (false, Some("public"), false),
+                    (true, None, false),
+                    (false, None, false),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn modifiers() {
+        let src = "contract C {
+            modifier onlyRole(bytes32 role, address account) { require(ok); _; }
+            function f() public onlyOwner nonReentrant(1) {}
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items.raw[0].kind else { panic!() };
+            let [modifier, function] = &contract.body[..] else { panic!() };
+
+            let ItemKind::Function(modifier) = &modifier.kind else { panic!() };
+            assert_eq!(modifier.kind, FunctionKind::Modifier);
+            assert_eq!(modifier.header.name.unwrap().as_str(), "onlyRole");
+            assert_eq!(modifier.header.parameters.len(), 2);
+            let body = modifier.body.as_ref().unwrap();
+            assert!(matches!(body[..], [_, Stmt { kind: StmtKind::Placeholder, .. }]));
+
+            let ItemKind::Function(function) = &function.kind else { panic!() };
+            assert_eq!(function.kind, FunctionKind::Function);
+            let modifiers = function
+                .header
+                .modifiers
+                .iter()
+                .map(|m| (m.name.to_string(), m.arguments.len()))
+                .collect::<Vec<_>>();
+            assert_eq!(modifiers, [("onlyOwner".into(), 0), ("nonReentrant".into(), 1)]);
+        });
+    }
+
+    #[test]
+    fn contract_kinds() {
+        let src = "contract C {} abstract contract A is C {} interface I {} library L {}";
+        with_source_unit(src, |unit| {
+            let kinds = unit
+                .items
+                .iter()
+                .map(|item| {
+                    let ItemKind::Contract(contract) = &item.kind else { panic!("{item:#?}") };
+                    (contract.kind, contract.name.to_string())
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                kinds,
+                [
+                    (ContractKind::Contract, "C".into()),
+                    (ContractKind::AbstractContract, "A".into()),
+                    (ContractKind::Interface, "I".into()),
+                    (ContractKind::Library, "L".into()),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn contract_body_order() {
+        let src = "contract C {
+            event E();
+            uint256 x;
+            struct S { uint256 a; }
+            function f() {}
+            error Err();
+            modifier m() { _; }
+            enum En { A }
+            using L for uint256;
+            uint256 y;
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items[0].kind else { panic!() };
+            let members = contract
+                .body
+                .iter()
+                .map(|item| {
+                    let name = item.name().map(|name| name.to_string()).unwrap_or_default();
+                    (item.description(), name)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                members,
+                [
+                    ("event definition", "E".into()),
+                    ("variable definition", "x".into()),
+                    ("struct definition", "S".into()),
+                    ("function definition", "f".into()),
+                    ("error definition", "Err".into()),
+                    ("function definition", "m".into()),
+                    ("enum definition", "En".into()),
+                    ("using directive", String::new()),
+                    ("variable definition", "y".into()),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn inheritdoc() {
+        let src = "contract C is I {
+            /// @inheritdoc I
+            function f() external {}
+            /**
+             * @notice Does things.
+             * @inheritdoc Lib.I2
+             */
+            function g() external {}
+            /// @inheritdocs I
+            function h() external {}
+            /// @inheritdoc Lib.
+            function i() external {}
+            /// @inheritdoc Lib..I2
+            function j() external {}
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items[0].kind else { panic!() };
+            let targets = contract
+                .body
+                .iter()
+                .map(|item| {
+                    let inheritdoc = item.docs.inheritdoc()?;
+                    Some(inheritdoc.path.iter().map(|ident| ident.to_string()).collect::<Vec<_>>())
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                targets,
+                [Some(vec!["I".into()]), Some(vec!["Lib".into(), "I2".into()]), None, None, None]
+            );
+        });
+    }
+
+    #[test]
+    fn misplaced_doc_comments() {
+        let src = "contract C {
+            function f() public returns (uint256) /// Between the header and the body.
+            {
+                return 1;
+            }
+            function g() public /// Before an empty body.
+            {}
+            function h() public {}
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items[0].kind else { panic!() };
+            for item in contract.body.iter() {
+                assert!(item.docs.is_empty(), "{item:#?}");
+                let ItemKind::Function(func) = &item.kind else { panic!() };
+                for stmt in func.body.iter().flat_map(|body| body.iter()) {
+                    assert!(stmt.docs.is_empty(), "{stmt:#?}");
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn enums() {
+        let src = "enum Color { Red, Green, Blue } contract C { enum Single { One } }";
+        with_source_unit(src, |unit| {
+            let format = |item: &Item<'_>| {
+                let ItemKind::Enum(enum_) = &item.kind else { panic!("{item:#?}") };
+                let variants = enum_.variants.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                format!("{} {{ {} }}", enum_.name, variants.join(", "))
+            };
+            assert_eq!(format(&unit.items.raw[0]), "Color { Red, Green, Blue }");
+            let ItemKind::Contract(contract) = &unit.items.raw[1].kind else { panic!() };
+            assert_eq!(format(&contract.body[0]), "Single { One }");
+        });
+    }
+
+    #[test]
+    fn structs() {
+        let src = "struct Point {
+            uint x;
+            mapping(uint => uint) m;
+            Point[] children;
+            Inner inner;
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Struct(struct_) = &unit.items.raw[0].kind else { panic!() };
+            assert_eq!(struct_.name.as_str(), "Point");
+            let fields = struct_
+                .fields
+                .iter()
+                .map(|field| {
+                    (field.name.unwrap().to_string(), solar_ast::print::ty_to_string(&field.ty))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                fields,
+                [
+                    ("x".into(), "uint256".into()),
+                    ("m".into(), "mapping(uint256 => uint256)".into()),
+                    ("children".into(), "Point[]".into()),
+                    ("inner".into(), "Inner".into()),
+                ]
+            );
+            assert!(struct_.fields.iter().all(|field| field.initializer.is_none()));
+        });
+    }
+
+    #[test]
+    fn events() {
+        let src = "event Transfer(address indexed from, address indexed to, uint256 value);
+        event Log(bytes32 indexed, uint, uint indexed, uint indexed, uint indexed) anonymous;";
+        with_source_unit(src, |unit| {
+            let events = unit
+                .items
+                .iter()
+                .map(|item| {
+                    let ItemKind::Event(event) = &item.kind else { panic!("{item:#?}") };
+                    let indexed = event.parameters.iter().map(|p| p.indexed).collect::<Vec<_>>();
+                    (event.name.to_string(), indexed, event.anonymous)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                events,
+                [
+                    ("Transfer".into(), vec![true, true, false], false),
+                    ("Log".into(), vec![true, false, true, true, true], true),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn state_variable_mutability() {
+        let src = "contract C {
+            uint256 public constant FOO = 1;
+            address public immutable owner;
+            uint256 constant internal BAR = 2;
+            uint256 immutable private baz;
+            uint256 qux;
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items.raw[0].kind else { panic!() };
+            let vars = contract
+                .body
+                .iter()
+                .map(|item| {
+                    let ItemKind::Variable(var) = &item.kind else { panic!("{item:#?}") };
+                    (var.mutability, var.visibility, var.initializer.is_some())
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                vars,
+                [
+                    (Some(VarMut::Constant), Some(Visibility::Public), true),
+                    (Some(VarMut::Immutable), Some(Visibility::Public), false),
+                    (Some(VarMut::Constant), Some(Visibility::Internal), true),
+                    (Some(VarMut::Immutable), Some(Visibility::Private), false),
+                    (None, None, false),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn from_in_imports() {
+        let src = r#"
+            import {from} from "a.sol";
+            import {from as from_, x as from} from "a.sol";
+            import * as from from "a.sol";
+            import "a.sol" as from;
+        "#;
+        with_source_unit(src, |unit| {
+            let imports = unit
+                .items
+                .iter()
+                .flat_map(|item| {
+                    let ItemKind::Import(import) = &item.kind else { panic!("{item:#?}") };
+                    match &import.items {
+                        ImportItems::Aliases(list) => list
+                            .iter()
+                            .map(|(name, alias)| {
+                                (Some(name.as_str()), alias.as_ref().map(Ident::as_str))
+                            })
+                            .collect::<Vec<_>>(),
+                        ImportItems::Glob(alias) | ImportItems::Plain(alias) => {
+                            vec![(None, alias.as_ref().map(Ident::as_str))]
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                imports,
+                [
+                    (Some("from"), None),
+                    (Some("from"), Some("from_")),
+                    (Some("x"), Some("from")),
+                    (None, Some("from")),
+                    (None, Some("from")),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn state_variable_initializers() {
+        let src = "contract C {
+            uint[] x = [1, 2, 3];
+            S s = S({a: 1});
+            uint256 constant N = type(uint256).max - f(2);
+            bytes32 immutable h = keccak256(abi.encode(x.length > 0 ? x[0] : 0));
+            uint y;
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items.raw[0].kind else { panic!() };
+            let inits = contract
+                .body
+                .iter()
+                .map(|item| {
+                    let ItemKind::Variable(var) = &item.kind else { panic!("{item:#?}") };
+                    var.initializer.as_deref().map(solar_ast::print::expr_to_string)
+                })
+                .collect::<Vec<_>>();
+            let expected = [
+                Some("[1, 2, 3]"),
+                Some("S({a: 1})"),
+                Some("type(uint256).max - f(2)"),
+                Some("keccak256(abi.encode(x.length > 0 ? x[0] : 0))"),
+                None,
+            ];
+            assert_eq!(inits.iter().map(Option::as_deref).collect::<Vec<_>>(), expected);
+
+            let ItemKind::Variable(var) = &contract.body[0].kind else { panic!() };
+            let ExprKind::Array(elems) = &var.initializer.as_ref().unwrap().kind else { panic!() };
+            assert_eq!(elems.len(), 3);
+            let ItemKind::Variable(var) = &contract.body[1].kind else { panic!() };
+            let ExprKind::Call(_, args) = &var.initializer.as_ref().unwrap().kind else { panic!() };
+            assert!(matches!(args, CallArgs::Named(_)));
+        });
+    }
+
+    #[test]
+    fn special_functions() {
+        let src = "contract C {
+            constructor() {}
+            receive() external payable {}
+            fallback(bytes calldata) external returns (bytes memory) {}
+            function receive() public {}
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items.raw[0].kind else { panic!() };
+            let kinds = contract
+                .body
+                .iter()
+                .map(|item| {
+                    let ItemKind::Function(f) = &item.kind else { panic!() };
+                    (f.kind, f.header.name.map(|name| name.to_string()))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                kinds,
+                [
+                    (FunctionKind::Constructor, None),
+                    (FunctionKind::Receive, None),
+                    (FunctionKind::Fallback, None),
+                    (FunctionKind::Function, Some("receive".into())),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn constructors() {
+        let src = "contract C is ERC20 {
+            constructor(uint256 x) ERC20(\"n\", \"s\") Ownable(msg.sender) onlyOwner {}
+        }
+        contract D {
+            constructor() payable internal {}
+        }";
+        with_source_unit(src, |unit| {
+            let constructor = |i: usize| {
+                let ItemKind::Contract(contract) = &unit.items.raw[i].kind else { panic!() };
+                let ItemKind::Function(f) = &contract.body[0].kind else { panic!() };
+                assert_eq!(f.kind, FunctionKind::Constructor);
+                assert_eq!(f.header.name, None);
+                f
+            };
+
+            let c = constructor(0);
+            assert_eq!(c.header.parameters.len(), 1);
+            let modifiers = c
+                .header
+                .modifiers
+                .iter()
+                .map(|m| (m.name.to_string(), m.arguments.len()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                modifiers,
+                [("ERC20".into(), 2), ("Ownable".into(), 1), ("onlyOwner".into(), 0)]
+            );
+
+            let d = constructor(1);
+            assert!(d.header.parameters.is_empty());
+            assert!(d.header.modifiers.is_empty());
+            assert_eq!(d.header.state_mutability, StateMutability::Payable);
+            assert_eq!(d.header.visibility, Some(Visibility::Internal));
+        });
+    }
+
+    #[test]
+    fn udvt_and_type_expr() {
+        let src = "type Price is uint128;
+        function f() pure returns (Price) { return Price.wrap(type(uint128).max); }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Udvt(udvt) = &unit.items.raw[0].kind else { panic!() };
+            assert_eq!(udvt.name.as_str(), "Price");
+            assert_eq!(solar_ast::print::ty_to_string(&udvt.ty), "uint128");
+            assert!(matches!(unit.items.raw[1].kind, ItemKind::Function(_)));
+        });
+    }
+
+    #[test]
+    fn data_locations() {
+        let src = "function f(
+            uint256[] memory a,
+            string calldata b,
+            S storage c,
+            uint256[] transient d,
+            uint256 e
+        ) {}";
+        with_source_unit(src, |unit| {
+            let ItemKind::Function(f) = &unit.items.raw[0].kind else { panic!() };
+            let locations =
+                f.header.parameters.iter().map(|param| param.data_location).collect::<Vec<_>>();
+            assert_eq!(
+                locations,
+                [
+                    Some(DataLocation::Memory),
+                    Some(DataLocation::Calldata),
+                    Some(DataLocation::Storage),
+                    Some(DataLocation::Transient),
+                    None,
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn payable() {
+        let src = "contract C {
+            address payable owner;
+            address other;
+            function f(address payable to) public payable {}
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items.raw[0].kind else { panic!() };
+            let [owner, other, f] = &contract.body[..] else { panic!() };
+            let address = |item: &Item<'_>| {
+                let ItemKind::Variable(var) = &item.kind else { panic!() };
+                let TypeKind::Elementary(ElementaryType::Address(payable)) = var.ty.kind else {
+                    panic!()
+                };
+                payable
+            };
+            assert!(address(owner));
+            assert!(!address(other));
+
+            let ItemKind::Function(f) = &f.kind else { panic!() };
+            assert_eq!(f.header.state_mutability, StateMutability::Payable);
+            let param = &f.header.parameters[0];
+            assert!(matches!(param.ty.kind, TypeKind::Elementary(ElementaryType::Address(true))));
+        });
+    }
+
+    #[test]
+    fn state_mutability() {
+        let src = "contract C {
+            function a() public view {}
+            function b() external payable {}
+            function c() pure virtual internal {}
+            function d() override public {}
+            function(uint256) external view returns (bool) e;
+        }";
+        with_source_unit(src, |unit| {
+            let ItemKind::Contract(contract) = &unit.items.raw[0].kind else { panic!() };
+            let mutabilities = contract
+                .body
+                .iter()
+                .map(|item| match &item.kind {
+                    ItemKind::Function(f) => (f.header.state_mutability, f.header.visibility),
+                    ItemKind::Variable(var) => {
+                        let TypeKind::Function(f) = &var.ty.kind else { panic!() };
+                        (f.state_mutability, f.visibility)
+                    }
+                    _ => panic!(),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                mutabilities,
+                [
+                    (StateMutability::View, Some(Visibility::Public)),
+                    (StateMutability::Payable, Some(Visibility::External)),
+                    (StateMutability::Pure, Some(Visibility::Internal)),
+                    (StateMutability::NonPayable, Some(Visibility::Public)),
+                    (StateMutability::View, Some(Visibility::External)),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn pragmas() {
+        let src = "pragma solidity ^0.8.0;
+        pragma abicoder v2;
+        pragma experimental ABIEncoderV2;
+        pragma experimental SMTChecker;
+        pragma experimental SomethingNew;
+        pragma solidity >=0.7.0 <0.9.0;";
+        with_source_unit(src, |unit| {
+            let versions = unit
+                .items
+                .iter()
+                .filter_map(|item| match &item.kind {
+                    ItemKind::Pragma(PragmaDirective { tokens: PragmaTokens::Version(_, req) }) => {
+                        Some(req.to_string())
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(versions, ["^0.8.0", ">=0.7.0 <0.9.0"]);
+
+            let pragmas = unit
+                .items
+                .iter()
+                .map(|item| {
+                    let ItemKind::Pragma(pragma) = &item.kind else { panic!() };
+                    pragma
+                        .tokens
+                        .as_name_and_value()
+                        .map(|(name, value)| (name.as_str(), value.map(IdentOrStrLit::as_str)))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                pragmas,
+                [
+                    None,
+                    Some(("abicoder", Some("v2"))),
+                    Some(("experimental", Some("ABIEncoderV2"))),
+                    Some(("experimental", Some("SMTChecker"))),
+                    Some(("experimental", Some("SomethingNew"))),
+                    None,
+                ]
+            );
+
+            let kinds = unit
+                .items
+                .iter()
+                .map(|item| {
+                    let ItemKind::Pragma(pragma) = &item.kind else { panic!() };
+                    pragma.tokens.kind()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                kinds,
+                [
+                    PragmaKind::Version,
+                    PragmaKind::Abicoder(AbiCoderVersion::V2),
+                    PragmaKind::Experimental(ExperimentalFeature::AbiEncoderV2),
+                    PragmaKind::Experimental(ExperimentalFeature::SmtChecker),
+                    PragmaKind::Experimental(ExperimentalFeature::Unknown("SomethingNew")),
+                    PragmaKind::Version,
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn node_spans() {
+        use solar_ast::visit::Visit;
+        use std::ops::ControlFlow;
+
+        /// Checks that every node has a real span that is nested in its parent's span.
+        #[derive(Default)]
+        struct SpanChecker {
+            stack: Vec<Span>,
+            count: usize,
+        }
+
+        impl SpanChecker {
+            fn enter(&mut self, kind: &str, span: Span) {
+                assert!(!span.is_dummy(), "{kind} has a dummy span");
+                if let Some(&parent) = self.stack.last() {
+                    assert!(parent.contains(span), "{kind} {span:?} is not inside {parent:?}");
+                }
+                self.stack.push(span);
+                self.count += 1;
+            }
+
+            fn exit(&mut self) -> ControlFlow<()> {
+                self.stack.pop();
+                ControlFlow::Continue(())
+            }
+        }
+
+        impl<'ast> Visit<'ast> for SpanChecker {
+            type BreakValue = ();
+
+            fn visit_item(&mut self, item: &'ast Item<'ast>) -> ControlFlow<()> {
+                self.enter("item", item.span);
+                self.walk_item(item)?;
+                self.exit()
+            }
+
+            fn visit_stmt(&mut self, stmt: &'ast Stmt<'ast>) -> ControlFlow<()> {
+                self.enter("statement", stmt.span);
+                self.walk_stmt(stmt)?;
+                self.exit()
+            }
+
+            fn visit_expr(&mut self, expr: &'ast Expr<'ast>) -> ControlFlow<()> {
+                self.enter("expression", expr.span);
+                self.walk_expr(expr)?;
+                self.exit()
+            }
+
+            fn visit_ty(&mut self, ty: &'ast Type<'ast>) -> ControlFlow<()> {
+                self.enter("type", ty.span);
+                self.walk_ty(ty)?;
+                self.exit()
+            }
+
+            fn visit_yul_stmt(&mut self, stmt: &'ast yul::Stmt<'ast>) -> ControlFlow<()> {
+                self.enter("Yul statement", stmt.span);
+                self.walk_yul_stmt(stmt)?;
+                self.exit()
+            }
+
+            fn visit_yul_expr(&mut self, expr: &'ast yul::Expr<'ast>) -> ControlFlow<()> {
+                self.enter("Yul expression", expr.span);
+                self.walk_yul_expr(expr)?;
+                self.exit()
+            }
+        }
+
+        let src = r#"
+            pragma solidity ^0.8.0;
+            import "a.sol";
+            using L for uint256;
+            type U is uint128;
+            error E(uint256 x);
+            event Ev(address indexed a);
+            struct S { uint256 a; mapping(address => uint256[]) m; }
+            enum Color { Red, Green }
+            uint256 constant X = 1 + 2 * 3;
+            function free(uint256 a) pure returns (uint256) { return a; }
+            contract C is I(1) {
+                uint256[] public xs;
+                function(uint256) external returns (bool) f;
+                modifier m(uint256 x) { _; }
+                function g(uint256 x) public m(x) returns (uint256 y) {
+                    (uint256 a, ) = (x, 2);
+                    for (uint256 i = 0; i < a; i++) { y += xs[i]; }
+                    while (y > 10) { y /= 2; }
+                    do { y--; } while (false);
+                    if (y == 0) revert E({ x: y }); else emit Ev(msg.sender);
+                    try this.g(1) returns (uint256) {} catch Error(string memory) {} catch {}
+                    unchecked { y = type(uint256).max + x ? 1 : y; }
+                    assembly {
+                        let z := add(y, 1)
+                        if iszero(z) { revert(0, 0) }
+                        switch z case 1 { z := 2 } default { leave }
+                        for { let k := 0 } lt(k, 3) { k := add(k, 1) } { continue }
+                        function h(p) -> q { q := mul(p, 2) }
+                    }
+                    return new C{ value: 1 }(1).g(0);
+                }
+            }
+        "#;
+        with_source_unit(src, |unit| {
+            let mut checker = SpanChecker::default();
+            let _ = checker.visit_source_unit(unit);
+            assert!(checker.stack.is_empty());
+            assert!(checker.count > 100, "{}", checker.count);
+        });
+    }
 }
@@ -4,7 +4,7 @@ use num_bigint::BigInt;
 use num_rational::BigRational;
 use num_traits::Num;
 use solar_ast::{token::*, *};
-use solar_interface::{diagnostics::ErrorGuaranteed, kw, Symbol};
+use solar_interface::{diagnostics::ErrorGuaranteed, error_code, kw, Symbol};
 use std::{borrow::Cow, fmt};
 
 impl<'sess, 'ast> Parser<'sess, 'ast> {
@@ -57,7 +57,11 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     }
 
     fn subdenomination(&self) -> Option<SubDenomination> {
-        match self.token.ident()?.name {
+        let name = self.token.ident()?.name;
+        if !self.is_keyword_enabled(name) {
+            return None;
+        }
+        match name {
             kw::Wei => Some(SubDenomination::Ether(EtherSubDenomination::Wei)),
             kw::Gwei => Some(SubDenomination::Ether(EtherSubDenomination::Gwei)),
             kw::Ether => Some(SubDenomination::Ether(EtherSubDenomination::Ether)),
@@ -110,7 +114,12 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     fn parse_lit_int(&mut self, symbol: Symbol) -> PResult<'sess, LitKind> {
         use LitError::*;
         match parse_integer(symbol) {
-            Ok(l) => Ok(l),
+            Ok(l) => {
+                if let LitKind::Number(_) = l {
+                    self.check_address_checksum(symbol);
+                }
+                Ok(l)
+            }
             // User error.
             Err(e @ IntegerLeadingZeros) => Err(self.dcx().err(e.to_string())),
             // User error, but already emitted.
@@ -125,6 +134,25 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
     }
 
+    /// Warns if the just-parsed integer literal looks like a mixed-case address but does not have
+    /// a valid EIP-55 checksum.
+    fn check_address_checksum(&mut self, symbol: Symbol) {
+        let s = &strip_underscores(&symbol)[..];
+        if s.len() != 42 || !s.starts_with("0x") {
+            return;
+        }
+        let digits = &s[2..];
+        let is_mixed_case = digits.bytes().any(|b| b.is_ascii_lowercase())
+            && digits.bytes().any(|b| b.is_ascii_uppercase());
+        if !is_mixed_case {
+            return;
+        }
+        let Ok(address) = s.parse::<Address>() else { return };
+        let msg = "this looks like an address but has an invalid checksum";
+        let help = format!("correct checksummed address: `{}`", address.to_checksum(None));
+        self.dcx().warn(msg).code(error_code!(9429)).span(self.prev_token.span).help(help).emit();
+    }
+
     /// Parses a rational literal.
     fn parse_lit_rational(&mut self, symbol: Symbol) -> PResult<'sess, LitKind> {
         use LitError::*;
@@ -155,6 +183,19 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         let mut value = unescape::parse_string_literal(lit.symbol.as_str(), mode);
         while let Some(TokenLit { symbol, kind }) = self.token.lit() {
             if kind != lit.kind {
+                if matches!(
+                    kind,
+                    TokenLitKind::Str | TokenLitKind::UnicodeStr | TokenLitKind::HexStr
+                ) {
+                    let msg = format!(
+                        "cannot concatenate {} and {} literals",
+                        lit.kind.description(),
+                        kind.description()
+                    );
+                    self.dcx().err(msg).span(self.token.span).emit();
+                    self.bump();
+                    continue;
+                }
                 break;
             }
             value
@@ -596,4 +637,79 @@ mod tests {
             check_rat("1.20", Ok("12/10"));
         });
     }
+
+    #[test]
+    fn str_concat() {
+        #[track_caller]
+        fn check(src: &str, expected_kind: StrKind, expected: &[u8]) {
+            let sess = Session::builder().with_test_emitter().build();
+            sess.enter(|| {
+                let arena = Arena::new();
+                let filename = solar_interface::source_map::FileName::Custom("test".into());
+                let mut parser =
+                    Parser::from_source_code(&sess, &arena, filename, src.into()).unwrap();
+                let lit = parser.parse_lit().map_err(|e| e.emit()).unwrap();
+                sess.dcx.has_errors().unwrap();
+                match &lit.kind {
+                    LitKind::Str(kind, value) => {
+                        assert_eq!(*kind, expected_kind, "{src:?}");
+                        assert_eq!(&value[..], expected, "{src:?}");
+                    }
+                    kind => panic!("not a string: {kind:?} ({src:?})"),
+                }
+            });
+        }
+
+        check(r#""a" "b""#, StrKind::Str, b"ab");
+        check(r#""a" 'b' "\x63""#, StrKind::Str, b"abc");
+        check(r#"unicode"a" unicode"\u00e9""#, StrKind::Unicode, "a\u{e9}".as_bytes());
+        check(r#"hex"00" hex"11""#, StrKind::Hex, &[0x00, 0x11]);
+        check(r#"hex"00_11_22" hex'33'"#, StrKind::Hex, &[0x00, 0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn subdenominations() {
+        #[track_caller]
+        fn check(src: &str, expected: &str, expected_sub: Option<SubDenomination>) {
+            let sess = Session::builder().with_test_emitter().build();
+            sess.enter(|| {
+                let arena = Arena::new();
+                let filename = solar_interface::source_map::FileName::Custom("test".into());
+                let mut parser =
+                    Parser::from_source_code(&sess, &arena, filename, src.into()).unwrap();
+                let (lit, sub) =
+                    parser.parse_lit_with_subdenomination().map_err(|e| e.emit()).unwrap();
+                sess.dcx.has_errors().unwrap();
+                let value = match &lit.kind {
+                    LitKind::Number(n) => n.to_string(),
+                    LitKind::Rational(r) => r.to_string(),
+                    kind => panic!("not a number: {kind:?} ({src:?})"),
+                };
+                assert_eq!(value, expected, "{src:?}");
+                assert_eq!(sub, expected_sub, "{src:?}");
+            });
+        }
+
+        use EtherSubDenomination::*;
+        use SubDenomination::{Ether as E, Time as T};
+        use TimeSubDenomination::*;
+
+        check("1.5e10", "15000000000", None);
+        check("2e-3", "1/500", None);
+        check("0x1A3F", "6719", None);
+        check("1_000_000", "1000000", None);
+
+        check("100 wei", "100", Some(E(Wei)));
+        check("2 gwei", "2000000000", Some(E(Gwei)));
+        check("0.1 ether", "100000000000000000", Some(E(Ether)));
+        check("1e-18 ether", "1", Some(E(Ether)));
+        check("1e-19 ether", "1/10", Some(E(Ether)));
+
+        check("1 seconds", "1", Some(T(Seconds)));
+        check("2 minutes", "120", Some(T(Minutes)));
+        check("1.5 hours", "5400", Some(T(Hours)));
+        check("1 days", "86400", Some(T(Days)));
+        check("1 weeks", "604800", Some(T(Weeks)));
+        check("1 years", "31536000", Some(T(Years)));
+    }
 }
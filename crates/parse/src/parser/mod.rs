@@ -1,13 +1,14 @@
-use crate::{Lexer, PErr, PResult};
+use crate::{Lexer, PErr, PResult, TokenCache};
 use smallvec::SmallVec;
 use solar_ast::{
     self as ast,
     token::{Delimiter, Token, TokenKind},
-    AstPath, Box, DocComment, DocComments, PathSlice,
+    AstPath, Box, DocComment, DocComments, PathSlice, SpdxLicense,
 };
 use solar_data_structures::{fmt::or_list, BumpExt};
 use solar_interface::{
     diagnostics::DiagCtxt,
+    error_code, kw,
     source_map::{FileName, SourceFile},
     Ident, Result, Session, Span, Symbol,
 };
@@ -47,6 +48,16 @@ pub struct Parser<'sess, 'ast> {
     in_yul: bool,
     /// Whether the parser is currently parsing a contract block.
     in_contract: bool,
+
+    /// The kinds of the recursive constructs currently being parsed, innermost last. Its length is
+    /// the current nesting depth.
+    nesting: Vec<&'static str>,
+    /// The maximum nesting depth. See [`Parser::DEFAULT_MAX_DEPTH`].
+    max_depth: usize,
+    /// The spans of the currently open braces, innermost last. Used for error recovery.
+    open_braces: Vec<Span>,
+    /// The SPDX license identifiers found in comments so far.
+    licenses: Vec<SpdxLicense>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -118,6 +129,12 @@ impl SeqSep {
 }
 
 impl<'sess, 'ast> Parser<'sess, 'ast> {
+    /// The default maximum nesting depth of expressions, statements, and types.
+    ///
+    /// Can be overridden with
+    /// [`Opts::max_parse_depth`](solar_interface::config::Opts::max_parse_depth).
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+
     /// Creates a new parser.
     pub fn new(sess: &'sess Session, arena: &'ast ast::Arena, tokens: Vec<Token>) -> Self {
         let mut parser = Self {
@@ -131,6 +148,10 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             tokens: tokens.into_iter(),
             in_yul: false,
             in_contract: false,
+            nesting: Vec::new(),
+            max_depth: sess.opts.max_parse_depth.unwrap_or(Self::DEFAULT_MAX_DEPTH),
+            open_braces: Vec::new(),
+            licenses: Vec::new(),
         };
         parser.bump();
         parser
@@ -189,6 +210,18 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         Self::from_lexer(arena, Lexer::from_source_file(sess, file))
     }
 
+    /// Creates a new parser from a source file, reusing its tokens from the session's
+    /// [`TokenCache`] if the file has not changed since it was last lexed.
+    ///
+    /// Note that the source file must be added to the source map before calling this function.
+    pub fn from_source_file_cached(
+        sess: &'sess Session,
+        arena: &'ast ast::Arena,
+        file: &SourceFile,
+    ) -> Self {
+        Self::new(sess, arena, TokenCache::from_session(sess).tokens(sess, file))
+    }
+
     /// Creates a new parser from a lexer.
     pub fn from_lexer(arena: &'ast ast::Arena, lexer: Lexer<'sess, '_>) -> Self {
         Self::new(lexer.sess, arena, lexer.into_tokens())
@@ -280,7 +313,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         let this_token_str = self.token.full_description();
         let label_exp = format!("expected `{t}`");
         let msg = format!("{label_exp}, found {this_token_str}");
-        let mut err = self.dcx().err(msg).span(span);
+        let mut err = self.dcx().err(msg).span(span).code(error_code!(2314));
         if !self.sess.source_map().is_multiline(prev_span.until(span)) {
             // When the spans are in the same line, it means that the only content
             // between them is whitespace, point only at the found token.
@@ -387,7 +420,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         };
 
         self.last_unexpected_token_span = Some(self.token.span);
-        let mut err = self.dcx().err(msg_exp).span(self.token.span);
+        let mut err = self.dcx().err(msg_exp).span(self.token.span).code(error_code!(2314));
 
         if self.prev_token.span.is_dummy()
             || !self
@@ -459,10 +492,31 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     /// An expectation is also added for diagnostics purposes.
     #[must_use]
     fn check_keyword(&mut self, kw: Symbol) -> bool {
+        if !self.is_keyword_enabled(kw) {
+            return false;
+        }
         self.expected_tokens.push(ExpectedToken::Keyword(kw));
         self.token.is_keyword(kw)
     }
 
+    /// Returns `true` if the next token is the given keyword, without eating it.
+    ///
+    /// Unlike [`check_keyword`](Self::check_keyword), no expectation is added, so this should be
+    /// used when dispatching on keywords that are not valid at this point in general, such as
+    /// contextual keywords or keywords that require further lookahead.
+    #[must_use]
+    fn peek_keyword(&self, kw: Symbol) -> bool {
+        self.token.is_keyword(kw) && self.is_keyword_enabled(kw)
+    }
+
+    /// Returns `true` if the next token is any of the given keywords, without eating it.
+    ///
+    /// See [`peek_keyword`](Self::peek_keyword).
+    #[must_use]
+    fn peek_keyword_any(&self, kws: &[Symbol]) -> bool {
+        kws.iter().any(|&kw| self.peek_keyword(kw))
+    }
+
     /// If the next token is the given keyword, eats it and returns `true`.
     /// Otherwise, returns `false`. An expectation is also added for diagnostics purposes.
     #[must_use]
@@ -487,6 +541,14 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
     }
 
+    /// Returns `true` if the next token is an identifier, without adding an expectation.
+    ///
+    /// See [`peek_keyword`](Self::peek_keyword).
+    #[must_use]
+    fn peek_ident(&self) -> bool {
+        self.token.is_ident()
+    }
+
     #[must_use]
     fn check_ident(&mut self) -> bool {
         self.check_or_expected(self.token.is_ident(), ExpectedToken::Ident)
@@ -494,7 +556,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
 
     #[must_use]
     fn check_nr_ident(&mut self) -> bool {
-        self.check_or_expected(self.token.is_non_reserved_ident(self.in_yul), ExpectedToken::Ident)
+        self.check_or_expected(self.is_nr_ident(&self.token), ExpectedToken::Ident)
     }
 
     #[must_use]
@@ -552,25 +614,6 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         self.parse_delim_seq(delim, SeqSep::trailing_disallowed(TokenKind::Comma), allow_empty, f)
     }
 
-    /// Parses a comma-separated sequence.
-    /// The function `f` must consume tokens until reaching the next separator.
-    #[track_caller]
-    #[inline]
-    fn parse_nodelim_comma_seq<T>(
-        &mut self,
-        stop: &TokenKind,
-        allow_empty: bool,
-        f: impl FnMut(&mut Self) -> PResult<'sess, T>,
-    ) -> PResult<'sess, Box<'ast, [T]>> {
-        self.parse_seq_to_before_end(
-            stop,
-            SeqSep::trailing_disallowed(TokenKind::Comma),
-            allow_empty,
-            f,
-        )
-        .map(|(v, _recovered)| v)
-    }
-
     /// Parses a `sep`-separated sequence, including both delimiters.
     /// The function `f` must consume tokens until reaching the next separator or
     /// closing bracket.
@@ -709,7 +752,8 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             }
             if !sep.trailing_sep_allowed && trailing {
                 let msg = format!("trailing `{sep_kind}` separator is not allowed");
-                self.dcx().err(msg).span(self.prev_token.span).emit();
+                let help = format!("remove the trailing `{sep_kind}`");
+                self.dcx().err(msg).span(self.prev_token.span).help(help).emit();
             }
         }
 
@@ -741,8 +785,39 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         if next.is_comment_or_doc() {
             self.dcx().bug("`bump_with` should not be used with comments").span(next.span).emit();
         }
+        self.track_open_braces();
         self.prev_token = std::mem::replace(&mut self.token, next);
         self.expected_tokens.clear();
+        // Doc-comments only document the token that immediately follows them.
+        self.docs.clear();
+    }
+
+    /// Updates `open_braces` with the current token, which is about to be bumped.
+    #[inline(always)]
+    fn track_open_braces(&mut self) {
+        match self.token.kind {
+            TokenKind::OpenDelim(Delimiter::Brace) => self.open_braces.push(self.token.span),
+            TokenKind::CloseDelim(Delimiter::Brace) => {
+                self.open_braces.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns an error for the innermost open brace, which was not closed before the end of the
+    /// file or the start of an item that cannot appear inside of it.
+    ///
+    /// The brace is considered closed afterwards, so that parsing can continue after the block.
+    #[cold]
+    fn unclosed_block_err(&mut self) -> PErr<'sess> {
+        let open = self.open_braces.pop().unwrap_or(self.token.span);
+        let mut err = self.dcx().err("unclosed block, expected `}`").span(open);
+        err = err.span_label(open, "unclosed block");
+        if !self.token.is_eof() {
+            let label = format!("expected `}}`, found {}", self.token.full_description());
+            err = err.span_label(self.token.span, label);
+        }
+        err
     }
 
     /// Bumps comments and docs.
@@ -753,11 +828,15 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         self.docs.clear();
 
         debug_assert!(next.is_comment_or_doc());
+        self.track_open_braces();
         self.prev_token = std::mem::replace(&mut self.token, next);
         while let Some((is_doc, doc)) = self.token.comment() {
             if is_doc {
                 self.docs.push(doc);
             }
+            if let Some(license) = SpdxLicense::from_comment(&doc) {
+                self.licenses.push(license);
+            }
             // Don't set `prev_token` on purpose.
             self.token = self.next_token();
         }
@@ -802,6 +881,29 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         res
     }
 
+    /// Runs `f` with an increased nesting depth, returning an error if the maximum depth is
+    /// exceeded instead of risking a stack overflow.
+    ///
+    /// The error names the kind of construct that makes up most of the nesting, rather than the
+    /// one that happened to cross the limit.
+    fn with_depth<T>(
+        &mut self,
+        what: &'static str,
+        f: impl FnOnce(&mut Self) -> PResult<'sess, T>,
+    ) -> PResult<'sess, T> {
+        if self.nesting.len() >= self.max_depth {
+            let count = |kind| self.nesting.iter().filter(|&&k| k == kind).count();
+            let what = self.nesting.iter().copied().max_by_key(|&k| count(k)).unwrap_or(what);
+            let msg = format!("{what} nested too deeply");
+            let note = format!("the maximum nesting depth is {}", self.max_depth);
+            return Err(self.dcx().err(msg).span(self.token.span).note(note));
+        }
+        self.nesting.push(what);
+        let res = f(self);
+        self.nesting.pop();
+        res
+    }
+
     /// Runs `f` with the parser in a Yul context.
     fn in_yul<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
         let old = std::mem::replace(&mut self.in_yul, true);
@@ -829,6 +931,45 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
     }
 
+    /// Tries to parse with `f`, returning `None` if `f` fails without consuming any tokens.
+    ///
+    /// Errors returned after `f` has consumed tokens are propagated. Note that diagnostics emitted
+    /// by `f` itself, rather than returned, are not rolled back. Expected tokens added by `f` are.
+    #[inline]
+    pub fn parse_opt<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> PResult<'sess, T>,
+    ) -> PResult<'sess, Option<T>> {
+        let start = self.token.span;
+        let expected_tokens = self.expected_tokens.len();
+        match f(self) {
+            Ok(t) => Ok(Some(t)),
+            Err(e) if self.token.span == start => {
+                e.cancel();
+                self.expected_tokens.truncate(expected_tokens);
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses a `sep`-separated sequence until one of `kets`, which is not consumed. A trailing
+    /// separator is not allowed.
+    ///
+    /// The function `f` must consume tokens until reaching the next separator or closing token.
+    #[track_caller]
+    #[inline]
+    pub fn parse_separated_until<T>(
+        &mut self,
+        sep: TokenKind,
+        kets: &[&TokenKind],
+        allow_empty: bool,
+        f: impl FnMut(&mut Self) -> PResult<'sess, T>,
+    ) -> PResult<'sess, Box<'ast, [T]>> {
+        self.parse_seq_to_before_tokens(kets, SeqSep::trailing_disallowed(sep), allow_empty, f)
+            .map(|(v, _recovered)| v)
+    }
+
     /// Parses contiguous doc comments. Can be empty.
     #[inline]
     pub fn parse_doc_comments(&mut self) -> DocComments<'ast> {
@@ -846,6 +987,35 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         docs.into()
     }
 
+    /// Returns `true` if `ident` is a reserved keyword in the current context.
+    ///
+    /// Outside of Yul, keywords that were introduced after the configured
+    /// [language version](solar_interface::config::Opts::language_version) are not reserved.
+    fn is_reserved(&self, ident: Ident) -> bool {
+        ident.is_reserved(self.in_yul) && self.is_keyword_enabled(ident.name)
+    }
+
+    /// Returns `true` if `kw` is a keyword in the configured
+    /// [language version](solar_interface::config::Opts::language_version).
+    ///
+    /// All keywords are enabled in Yul.
+    fn is_keyword_enabled(&self, kw: Symbol) -> bool {
+        if self.in_yul {
+            return true;
+        }
+        let Some(version) = &self.sess.opts.language_version else { return true };
+        match reserved_since(kw) {
+            Some(since) => (version.major, version.minor, version.patch) >= since,
+            None => true,
+        }
+    }
+
+    /// Returns `true` if `token` is an identifier, but not a reserved keyword in the current
+    /// context. See [`is_reserved`](Self::is_reserved).
+    fn is_nr_ident(&self, token: &Token) -> bool {
+        token.ident().is_some_and(|ident| !self.is_reserved(ident))
+    }
+
     /// Parses a qualified identifier: `foo.bar.baz`.
     #[track_caller]
     pub fn parse_path(&mut self) -> PResult<'sess, AstPath<'ast>> {
@@ -926,7 +1096,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     #[track_caller]
     fn parse_ident_common(&mut self, recover: bool) -> PResult<'sess, Ident> {
         let ident = self.ident_or_err(recover)?;
-        if ident.is_reserved(self.in_yul) {
+        if self.is_reserved(ident) {
             let err = self.expected_ident_found_err();
             if recover {
                 err.emit();
@@ -956,7 +1126,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     fn expected_ident_found_other(&mut self, token: Token, recover: bool) -> PResult<'sess, Ident> {
         let msg = format!("expected identifier, found {}", token.full_description());
         let span = token.span;
-        let mut err = self.dcx().err(msg).span(span);
+        let mut err = self.dcx().err(msg).span(span).code(error_code!(2314));
 
         let mut recovered_ident = None;
 
@@ -983,3 +1153,256 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         self.expected_ident_found(false).unwrap_err()
     }
 }
+
+/// Keywords that were introduced after Solidity 0.4.0, and the version since which they are
+/// reserved.
+const VERSIONED_KEYWORDS: &[(Symbol, (u64, u64, u64))] = &[
+    (kw::View, (0, 4, 17)),
+    (kw::Pure, (0, 4, 17)),
+    (kw::Emit, (0, 4, 21)),
+    (kw::Constructor, (0, 4, 22)),
+    (kw::Alias, (0, 5, 0)),
+    (kw::Apply, (0, 5, 0)),
+    (kw::Auto, (0, 5, 0)),
+    (kw::Calldata, (0, 5, 0)),
+    (kw::CopyOf, (0, 5, 0)),
+    (kw::Define, (0, 5, 0)),
+    (kw::Immutable, (0, 5, 0)),
+    (kw::Implements, (0, 5, 0)),
+    (kw::Macro, (0, 5, 0)),
+    (kw::Mutable, (0, 5, 0)),
+    (kw::Override, (0, 5, 0)),
+    (kw::Partial, (0, 5, 0)),
+    (kw::Promise, (0, 5, 0)),
+    (kw::Reference, (0, 5, 0)),
+    (kw::Sealed, (0, 5, 0)),
+    (kw::Sizeof, (0, 5, 0)),
+    (kw::Supports, (0, 5, 0)),
+    (kw::Typedef, (0, 5, 0)),
+    (kw::Unchecked, (0, 5, 0)),
+    (kw::Fallback, (0, 6, 0)),
+    (kw::Receive, (0, 6, 0)),
+    (kw::Virtual, (0, 6, 0)),
+    (kw::Gwei, (0, 7, 0)),
+    (kw::Unicode, (0, 7, 0)),
+];
+
+/// Returns the version since which `name` is a reserved keyword, if it was introduced after
+/// Solidity 0.4.0.
+fn reserved_since(name: Symbol) -> Option<(u64, u64, u64)> {
+    VERSIONED_KEYWORDS.iter().find(|&&(kw, _)| kw == name).map(|&(_, since)| since)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solar_ast::print::source_unit_to_string;
+    use solar_interface::BytePos;
+
+    /// Parses and pretty-prints the given source code.
+    fn print(sess: &Session, name: &str, src: String) -> String {
+        let arena = ast::Arena::new();
+        let mut parser =
+            Parser::from_source_code(sess, &arena, FileName::Custom(name.into()), src).unwrap();
+        let unit = parser.parse_file().map_err(|e| e.emit()).unwrap();
+        sess.dcx.has_errors().unwrap();
+        source_unit_to_string(&unit)
+    }
+
+    #[track_caller]
+    fn assert_roundtrip(sess: &Session, name: &str, src: &str) {
+        let printed = print(sess, name, src.into());
+        let reprinted = print(sess, &format!("{name}.printed"), printed.clone());
+        assert_eq!(printed, reprinted, "pretty-printing is not idempotent for {name}");
+    }
+
+    #[test]
+    fn print_roundtrip() {
+        let src = r#"
+pragma solidity ^0.8.0 || >=0.4.0 <0.5.0;
+pragma abicoder v2;
+import "a.sol";
+import 'b"c.sol' as B;
+import {X, Y as Z} from "c.sol";
+import * as W from "d.sol";
+using L for uint256;
+using {f, g as +, h as ~} for T global;
+type T is uint128;
+error E(uint256 a, string);
+event Ev(address indexed from, uint256 value) anonymous;
+enum Color { Red, Green }
+struct S { uint256 a; mapping(address owner => uint256[]) m; }
+/// @notice A contract.
+abstract contract C is A, B(1, "x") {
+    uint256 public constant X = 1 ether + 2 gwei;
+    function(uint256) external view returns (bool) f;
+    modifier m() { _; }
+    constructor(uint256 a) payable B(a) {}
+    receive() external payable {}
+    fallback(bytes calldata) external returns (bytes memory) {}
+    function g(uint256[] memory xs) public pure virtual override(A, B) m returns (uint256 r) {
+        for (uint256 i = 0; i < xs.length; i++) r += xs[i];
+        for (;;) { break; }
+        if (r > 1) return r; else if (r == 0) { revert E(1, "a"); } else r = -(-r);
+        while (true) continue;
+        do { r--; } while (r > 0);
+        (uint256 a, , bool b) = (1, 2, true);
+        (a, ) = f({x: 1});
+        x = a > 0 ? hex"00ff" hex"01" : unicode"h€llo\n" unicode'a"b';
+        w = "a\x00\"" 'b' "c";
+        y = new uint256[](3);
+        z = payable(msg.sender).call{value: 1, gas: 2}("");
+        delete m[a][b];
+        u = type(uint256).max + s[1:].length + s[:2].length;
+        unchecked { r = r ** 2 ** 3 - ~r; }
+        emit Ev(a, 0x1234_5678);
+        try this.g(xs) returns (uint256 v) {} catch Error(string memory e) {} catch (bytes memory) {} catch {}
+        assembly "evmasm" ("memory-safe") {
+            let a, b := f(1, "x")
+            a := 0x20
+            if lt(a, b) { leave }
+            for { let i := 0 } lt(i, 10) { i := add(i, 1) } { continue }
+            switch a case 0 { } case "y" { b := 1 } default { }
+            function h(p) -> q, t { q := p }
+        }
+    }
+    function n() external;
+}
+"#;
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            assert_roundtrip(&sess, "inline", src);
+
+            let testdata = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../testdata");
+            for entry in std::fs::read_dir(testdata).unwrap() {
+                let path = entry.unwrap().path();
+                if path.extension().is_some_and(|ext| ext == "sol") {
+                    let src = std::fs::read_to_string(&path).unwrap();
+                    assert_roundtrip(&sess, &path.display().to_string(), &src);
+                }
+            }
+        });
+    }
+
+    /// Parses `src` with `f`, asserting that no errors were emitted.
+    #[track_caller]
+    fn with_parser(src: &str, f: impl FnOnce(&mut Parser<'_, '_>)) {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| -> Result {
+            let arena = ast::Arena::new();
+            let filename = FileName::Custom("test".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, filename, src.into())?;
+            f(&mut parser);
+            sess.dcx.has_errors()
+        })
+        .unwrap_or_else(|_| panic!("src: {src:?}"));
+    }
+
+    #[test]
+    fn parse_opt() {
+        with_parser("a + 1; 2", |parser| {
+            let ident = parser.parse_opt(Parser::parse_ident).unwrap();
+            assert_eq!(ident.unwrap().as_str(), "a");
+
+            // `+` does not start an identifier or a literal.
+            parser.expected_tokens.clear();
+            assert!(parser.parse_opt(Parser::parse_ident).unwrap().is_none());
+            assert!(parser.parse_opt(Parser::parse_lit).unwrap().is_none());
+            assert!(parser.expected_tokens.is_empty(), "{:?}", parser.expected_tokens);
+            assert!(parser.eat(&TokenKind::BinOp(solar_ast::token::BinOpToken::Plus)));
+
+            let lit = parser.parse_opt(Parser::parse_lit).unwrap();
+            assert_eq!(lit.unwrap().symbol.as_str(), "1");
+
+            // Errors after consuming tokens are propagated.
+            let res = parser.parse_opt(|this| {
+                this.expect(&TokenKind::Semi)?;
+                this.parse_ident()
+            });
+            res.unwrap_err().cancel();
+            assert_eq!(parser.prev_token.kind, TokenKind::Semi);
+        });
+    }
+
+    #[test]
+    fn parse_separated_until() {
+        with_parser("a, b, c; ;", |parser| {
+            let idents = parser
+                .parse_separated_until(
+                    TokenKind::Comma,
+                    &[&TokenKind::Semi],
+                    false,
+                    Parser::parse_ident,
+                )
+                .unwrap();
+            let idents = idents.iter().map(|ident| ident.as_str()).collect::<Vec<_>>();
+            assert_eq!(idents, ["a", "b", "c"]);
+            assert!(parser.eat(&TokenKind::Semi));
+
+            let empty = parser
+                .parse_separated_until(
+                    TokenKind::Comma,
+                    &[&TokenKind::Semi],
+                    true,
+                    Parser::parse_ident,
+                )
+                .unwrap();
+            assert!(empty.is_empty());
+            assert!(parser.eat(&TokenKind::Semi));
+        });
+    }
+
+    #[test]
+    fn error_codes() {
+        let sess =
+            Session::builder().with_buffer_emitter(solar_interface::ColorChoice::Never).build();
+        sess.enter(|| {
+            let arena = ast::Arena::new();
+            let src = "function f() { while (true) { break } }".to_string();
+            let mut parser =
+                Parser::from_source_code(&sess, &arena, FileName::Custom("test".into()), src)
+                    .unwrap();
+            let _ = parser.parse_file().map_err(|e| e.emit());
+        });
+        let emitted = sess.emitted_diagnostics().unwrap().to_string();
+        assert!(emitted.contains("error[2314]: expected `;`, found `}`"), "{emitted}");
+    }
+
+    #[test]
+    fn find_at() {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let arena = ast::Arena::new();
+            let src = "contract C {\n    function f(uint256 a) {\n        a = b + c;\n    }\n}";
+            let mut parser = Parser::from_source_code(
+                &sess,
+                &arena,
+                FileName::Custom("test".into()),
+                src.into(),
+            )
+            .unwrap();
+            let unit = parser.parse_file().map_err(|e| e.emit()).unwrap();
+            let file = sess.source_map().files()[0].clone();
+            let offset = |s: &str| file.start_pos + BytePos::from_usize(src.find(s).unwrap());
+            let snippet = |span: Span| sess.source_map().span_to_snippet(span).unwrap();
+
+            let b = offset("b +");
+            assert_eq!(unit.ident_at(b).unwrap().as_str(), "b");
+            assert_eq!(snippet(unit.span_at(b).unwrap()), "b");
+            // Between tokens, the innermost enclosing node is selected.
+            assert_eq!(snippet(unit.span_at(b + BytePos(1)).unwrap()), "b + c");
+            assert_eq!(unit.ident_at(b + BytePos(1)).unwrap().as_str(), "b");
+            // Identifiers ending at the position are also found.
+            assert_eq!(unit.ident_at(offset(" = b")).unwrap().as_str(), "a");
+            assert_eq!(
+                snippet(unit.span_at(offset("(uint256")).unwrap()),
+                "function f(uint256 a) {\n        a = b + c;\n    }"
+            );
+            // At the end of the file, the last node is selected.
+            let eof = file.start_pos + BytePos::from_usize(src.len());
+            assert_eq!(snippet(unit.span_at(eof).unwrap()), src);
+            assert_eq!(unit.ident_at(eof), None);
+            assert_eq!(unit.span_at(eof + BytePos(1)), None);
+        });
+    }
+}
@@ -1,5 +1,5 @@
 use super::item::VarFlags;
-use crate::{parser::SeqSep, PResult, Parser};
+use crate::{PResult, Parser};
 use smallvec::SmallVec;
 use solar_ast::{token::*, *};
 use solar_data_structures::BumpExt;
@@ -10,7 +10,8 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     #[instrument(level = "debug", skip_all)]
     pub fn parse_stmt(&mut self) -> PResult<'sess, Stmt<'ast>> {
         let docs = self.parse_doc_comments();
-        self.parse_spanned(Self::parse_stmt_kind).map(|(span, kind)| Stmt { docs, kind, span })
+        self.with_depth("statement", |this| this.parse_spanned(Self::parse_stmt_kind))
+            .map(|(span, kind)| Stmt { docs, kind, span })
     }
 
     /// Parses a statement into a new allocation.
@@ -45,7 +46,8 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         } else if self.eat_keyword(kw::Return) {
             let expr = if self.check(&TokenKind::Semi) { None } else { Some(self.parse_expr()?) };
             Ok(StmtKind::Return(expr))
-        } else if self.eat_keyword(kw::Throw) {
+        } else if self.peek_keyword(kw::Throw) {
+            self.bump(); // `throw`
             let msg = "`throw` statements have been removed; use `revert`, `require`, or `assert` instead";
             Err(self.dcx().err(msg).span(self.prev_token.span))
         } else if self.eat_keyword(kw::Try) {
@@ -56,11 +58,10 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             self.parse_stmt_assembly().map(StmtKind::Assembly)
         } else if self.eat_keyword(kw::Emit) {
             self.parse_path_call().map(|(path, params)| StmtKind::Emit(path, params))
-        } else if self.check_keyword(kw::Revert) && self.look_ahead(1).is_ident() {
+        } else if self.peek_keyword(kw::Revert) && self.look_ahead(1).is_ident() {
             self.bump(); // `revert`
             self.parse_path_call().map(|(path, params)| StmtKind::Revert(path, params))
-        } else if self.check_keyword(sym::underscore) && self.look_ahead(1).kind == TokenKind::Semi
-        {
+        } else if self.peek_keyword(sym::underscore) && self.look_ahead(1).kind == TokenKind::Semi {
             self.bump(); // `_`
             Ok(StmtKind::Placeholder)
         } else {
@@ -74,7 +75,24 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
 
     /// Parses a block of statements.
     pub(super) fn parse_block(&mut self) -> PResult<'sess, Block<'ast>> {
-        self.parse_delim_seq(Delimiter::Brace, SeqSep::none(), true, Self::parse_stmt)
+        self.expect(&TokenKind::OpenDelim(Delimiter::Brace))?;
+        let mut stmts = SmallVec::<[_; 8]>::new();
+        while !self.check(&TokenKind::CloseDelim(Delimiter::Brace)) {
+            if self.token.is_eof() {
+                return Err(self.unclosed_block_err());
+            }
+            if self.is_item_start() {
+                // The closing brace is likely missing; end the block here and parse the item.
+                self.unclosed_block_err().emit();
+                return Ok(self.alloc_smallvec(stmts));
+            }
+            if let TokenKind::CloseDelim(_) = self.token.kind {
+                break;
+            }
+            stmts.push(self.parse_stmt()?);
+        }
+        self.expect(&TokenKind::CloseDelim(Delimiter::Brace))?;
+        Ok(self.alloc_smallvec(stmts))
     }
 
     /// Parses an if statement.
@@ -298,9 +316,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
 
         let iap = self.parse_iap()?;
-        let ty = if self.token.is_non_reserved_ident(self.in_yul)
-            || self.token.is_location_specifier()
-        {
+        let ty = if self.is_nr_ident(&self.token) || self.token.is_location_specifier() {
             // `a.b memory`, `a[b] c`
             LookAheadInfo::VariableDeclaration
         } else {
@@ -311,20 +327,22 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
 
     fn peek_statement_type(&mut self) -> LookAheadInfo {
         // https://github.com/ethereum/solidity/blob/194b114664c7daebc2ff68af3c573272f5d28913/libsolidity/parsing/Parser.cpp#L2528
-        if self.token.is_keyword_any(&[kw::Mapping, kw::Function]) {
+        if self.peek_keyword_any(&[kw::Mapping, kw::Function]) {
             return LookAheadInfo::VariableDeclaration;
         }
 
-        if self.check_nr_ident() || self.check_elementary_type() {
+        if self.is_nr_ident(&self.token) || self.token.is_elementary_type() {
             let next = self.look_ahead(1);
             if self.token.is_elementary_type() && next.is_ident_where(|id| id.name == kw::Payable) {
                 return LookAheadInfo::VariableDeclaration;
             }
-            if next.is_non_reserved_ident(self.in_yul)
+            if self.is_nr_ident(next)
                 || next.is_location_specifier()
                 // These aren't valid but we include them for a better error message.
                 || next.is_mutability_specifier()
                 || next.is_visibility_specifier()
+                // Keywords used as variable names, such as `uint indexed`.
+                || next.is_reserved_ident(false)
             {
                 return LookAheadInfo::VariableDeclaration;
             }
@@ -342,7 +360,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             path.push(IapKind::Member(self.parse_ident()?));
             while self.eat(&TokenKind::Dot) {
                 let id = self.ident_or_err(true)?;
-                if id.name != kw::Address && id.is_reserved(self.in_yul) {
+                if id.name != kw::Address && self.is_reserved(id) {
                     self.expected_ident_found_err().emit();
                 }
                 self.bump(); // `id`
@@ -469,6 +487,22 @@ mod tests {
     use super::*;
     use solar_interface::{source_map::FileName, Result, Session};
 
+    /// Parses `src` as a statement, asserting that no errors were emitted.
+    #[track_caller]
+    fn with_stmt(src: &str, f: impl for<'a> FnOnce(&'a Stmt<'a>)) {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| -> Result {
+            let arena = Arena::new();
+            let filename = FileName::Custom("test".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, filename, src.into())?;
+            let stmt = parser.parse_stmt().map_err(|e| e.emit())?;
+            sess.dcx.has_errors()?;
+            f(arena.alloc(stmt));
+            Ok(())
+        })
+        .unwrap_or_else(|_| panic!("src: {src:?}"));
+    }
+
     #[test]
     fn optional_items_seq() {
         fn check(tests: &[(&str, &[Option<&str>])]) {
@@ -511,4 +545,119 @@ mod tests {
             ("(a,,c)", &[Some("a"), None, Some("c")]),
         ]);
     }
+
+    #[test]
+    fn emit() {
+        with_stmt("emit Transfer(from, to, 1);", |stmt| {
+            let StmtKind::Emit(path, CallArgs::Unnamed(args)) = &stmt.kind else { panic!() };
+            assert_eq!(path.to_string(), "Transfer");
+            assert_eq!(args.len(), 3);
+        });
+        with_stmt("emit Lib.Event();", |stmt| {
+            let StmtKind::Emit(path, args) = &stmt.kind else { panic!() };
+            let segments = path.segments().iter().map(|s| s.as_str()).collect::<Vec<_>>();
+            assert_eq!(segments, ["Lib", "Event"]);
+            assert!(args.is_empty());
+        });
+        with_stmt("emit Transfer({to: b, from: a});", |stmt| {
+            let StmtKind::Emit(_, CallArgs::Named(args)) = &stmt.kind else { panic!() };
+            let names = args.iter().map(|arg| arg.name.as_str()).collect::<Vec<_>>();
+            assert_eq!(names, ["to", "from"]);
+        });
+    }
+
+    #[test]
+    fn declaration_or_expression() {
+        #[track_caller]
+        fn decl(src: &str, expected_ty: &str, expected_name: &str) {
+            with_stmt(src, |stmt| {
+                let StmtKind::DeclSingle(var) = &stmt.kind else { panic!("{stmt:?}") };
+                assert_eq!(solar_ast::print::ty_to_string(&var.ty), expected_ty);
+                assert_eq!(var.name.unwrap().as_str(), expected_name);
+            });
+        }
+        #[track_caller]
+        fn expr(src: &str) {
+            with_stmt(src, |stmt| {
+                assert!(matches!(stmt.kind, StmtKind::Expr(..)), "{stmt:?}");
+            });
+        }
+
+        decl("MyStruct.Inner x;", "MyStruct.Inner", "x");
+        decl("A.B c = d;", "A.B", "c");
+        decl("A.B.C[] memory c;", "A.B.C[]", "c");
+        decl("A.B[2][] storage c = d;", "A.B[2][]", "c");
+        decl("uint[] memory c;", "uint256[]", "c");
+        decl("uint[a > b ? 1 : 2] memory c;", "uint256[a > b ? 1 : 2]", "c");
+        decl("uint[N * 2 + 1][] memory c;", "uint256[N * 2 + 1][]", "c");
+        expr("a.b.c;");
+        expr("a.b.c = d;");
+        expr("a.b[1].c = 2;");
+        expr("a[1][2];");
+        expr("a.b(c);");
+
+        with_stmt("unchecked { A.B c = d; a.b.c++; }", |stmt| {
+            let StmtKind::UncheckedBlock(block) = &stmt.kind else { panic!("{stmt:?}") };
+            assert!(matches!(block[0].kind, StmtKind::DeclSingle(..)));
+            assert!(matches!(block[1].kind, StmtKind::Expr(..)));
+        });
+    }
+
+    #[test]
+    fn try_clause_params() {
+        let src = "try c.f() returns (uint256 x) { x; } \
+                   catch Error(string memory reason) { reason; } \
+                   catch Panic(uint256 code) { code; } \
+                   catch (bytes memory data) { data; } \
+                   catch { }";
+        with_stmt(src, |stmt| {
+            let StmtKind::Try(try_) = &stmt.kind else { panic!("{stmt:?}") };
+            let params = |clause: &TryCatchClause<'_>| {
+                clause.args.iter().map(|p| p.name.unwrap().to_string()).collect::<Vec<_>>()
+            };
+
+            let success = try_.success();
+            assert!(success.name.is_none());
+            assert_eq!(params(success), ["x"]);
+            assert_eq!(success.block.len(), 1);
+
+            let catches = try_.catches();
+            let names = catches.iter().map(|c| c.name.map(|n| n.to_string())).collect::<Vec<_>>();
+            assert_eq!(names, [Some("Error".into()), Some("Panic".into()), None, None]);
+            assert_eq!(params(&catches[0]), ["reason"]);
+            assert_eq!(params(&catches[1]), ["code"]);
+            assert_eq!(params(&catches[2]), ["data"]);
+            assert!(catches[3].args.is_empty());
+        });
+        with_stmt("try c.f() { } catch { }", |stmt| {
+            let StmtKind::Try(try_) = &stmt.kind else { panic!("{stmt:?}") };
+            assert!(try_.success().args.is_empty());
+            assert_eq!(try_.catches().len(), 1);
+        });
+    }
+
+    #[test]
+    fn assembly_referenced_externals() {
+        #[track_caller]
+        fn check(src: &str, expected: &[&str]) {
+            with_stmt(src, |stmt| {
+                let StmtKind::Assembly(assembly) = &stmt.kind else { panic!("{stmt:?}") };
+                let names = assembly.referenced_externals();
+                let names = names.iter().map(|i| i.as_str());
+                assert_eq!(names.collect::<Vec<_>>(), expected, "{src:?}");
+            });
+        }
+
+        check("assembly { sstore(0, x) }", &["x"]);
+        check("assembly { let y := x y := add(y, x) }", &["x"]);
+        check("assembly { let x := x }", &["x"]);
+        check("assembly { { let y := 1 } sstore(y, z.slot) }", &["y", "z"]);
+        check(
+            "assembly { for { let i := 0 } lt(i, n) { i := add(i, 1) } { r := i } }",
+            &["n", "r"],
+        );
+        check("assembly { switch x case 0 { let y := 1 } default { y := 2 } }", &["x", "y"]);
+        check("assembly { let a function f(p) -> q { q := add(p, a) } a := f(b) }", &["a", "b"]);
+        check("assembly { f() function f() {} }", &[]);
+    }
 }
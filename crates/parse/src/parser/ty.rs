@@ -9,7 +9,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     #[instrument(level = "debug", skip_all)]
     pub fn parse_type(&mut self) -> PResult<'sess, Type<'ast>> {
         let mut ty = self
-            .parse_spanned(Self::parse_basic_ty_kind)
+            .with_depth("type", |this| this.parse_spanned(Self::parse_basic_ty_kind))
             .map(|(span, kind)| Type { span, kind })?;
 
         // Parse suffixes.
@@ -238,6 +238,51 @@ fn parse_ty_size_u8(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solar_interface::{source_map::FileName, Result, Session};
+
+    #[test]
+    fn array_sizes() {
+        /// Checks the sizes of the array suffixes of the type, from outermost to innermost, and
+        /// the innermost element type.
+        #[track_caller]
+        fn check(src: &str, expected_sizes: &[Option<&str>], expected_elem: &str) {
+            let sess = Session::builder().with_test_emitter().build();
+            sess.enter(|| -> Result {
+                let arena = Arena::new();
+                let filename = FileName::Custom("test".into());
+                let mut parser = Parser::from_source_code(&sess, &arena, filename, src.into())?;
+                let ty = parser.parse_type().map_err(|e| e.emit())?;
+                sess.dcx.has_errors()?;
+
+                let mut sizes = Vec::new();
+                let mut ty = &ty;
+                while let TypeKind::Array(array) = &ty.kind {
+                    sizes.push(array.size.as_deref().map(solar_ast::print::expr_to_string));
+                    ty = &array.element;
+                }
+                let sizes = sizes.iter().map(Option::as_deref).collect::<Vec<_>>();
+                assert_eq!(sizes, expected_sizes, "{src:?}");
+                assert_eq!(solar_ast::print::ty_to_string(ty), expected_elem, "{src:?}");
+                Ok(())
+            })
+            .unwrap_or_else(|_| panic!("src: {src:?}"));
+        }
+
+        check("uint[]", &[None], "uint256");
+        check("uint256[3]", &[Some("3")], "uint256");
+        check("bytes32[2 * 3]", &[Some("2 * 3")], "bytes32");
+        check("address[CONSTANT]", &[Some("CONSTANT")], "address");
+        check("uint[2 * K + L.M]", &[Some("2 * K + L.M")], "uint256");
+        check("uint[2][]", &[None, Some("2")], "uint256");
+        check("S[][N - 1][]", &[None, Some("N - 1"), None], "S");
+        check("uint[a > b ? 1 : 2]", &[Some("a > b ? 1 : 2")], "uint256");
+        check(
+            "uint[(a < b) == c ? x[1] : f(2)][]",
+            &[None, Some("(a < b) == c ? x[1] : f(2)")],
+            "uint256",
+        );
+        check("uint[1 << 2 | 3 ** 2 % 4]", &[Some("1 << 2 | 3 ** 2 % 4")], "uint256");
+    }
 
     #[test]
     fn parse_size() {
@@ -1,7 +1,10 @@
 use super::SeqSep;
 use crate::{PResult, Parser};
 use smallvec::SmallVec;
-use solar_ast::{token::*, yul::*, AstPath, Box, DocComments, LitKind, PathSlice, StrKind, StrLit};
+use solar_ast::{
+    token::*, yul::*, AstPath, BinOpKind, Box, DocComments, Lit, LitKind, PathSlice, StrKind,
+    StrLit,
+};
 use solar_interface::{error_code, kw, sym, Ident};
 
 impl<'sess, 'ast> Parser<'sess, 'ast> {
@@ -42,7 +45,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         loop {
             let docs = self.parse_doc_comments();
             if self.check_keyword(sym::object) {
-                children.push(self.parse_yul_object(docs)?);
+                children.push(self.with_depth("object", |this| this.parse_yul_object(docs))?);
             } else if self.check_keyword(sym::data) {
                 data.push(self.parse_yul_data()?);
             } else {
@@ -88,7 +91,8 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     /// Parses a Yul statement, without setting `in_yul`.
     pub fn parse_yul_stmt_unchecked(&mut self) -> PResult<'sess, Stmt<'ast>> {
         let docs = self.parse_doc_comments();
-        self.parse_spanned(Self::parse_yul_stmt_kind).map(|(span, kind)| Stmt { docs, span, kind })
+        self.with_depth("statement", |this| this.parse_spanned(Self::parse_yul_stmt_kind))
+            .map(|(span, kind)| Stmt { docs, span, kind })
     }
 
     /// Parses a Yul block.
@@ -148,6 +152,9 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             } else {
                 self.unexpected()
             }
+        } else if self.token.kind == TokenKind::Semi {
+            let msg = "Yul statements are not terminated by semicolons";
+            Err(self.dcx().err(msg).span(self.token.span).help("remove this `;`"))
         } else {
             self.unexpected()
         }
@@ -172,8 +179,9 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         let name = self.parse_ident()?;
         let parameters = self.parse_paren_comma_seq(true, Self::parse_ident)?;
         let returns = if self.eat(&TokenKind::Arrow) {
-            self.parse_nodelim_comma_seq(
-                &TokenKind::OpenDelim(Delimiter::Brace),
+            self.parse_separated_until(
+                TokenKind::Comma,
+                &[&TokenKind::OpenDelim(Delimiter::Brace)],
                 false,
                 Self::parse_ident,
             )?
@@ -188,6 +196,19 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     fn parse_yul_stmt_if(&mut self) -> PResult<'sess, StmtKind<'ast>> {
         let cond = self.parse_yul_expr()?;
         let body = self.parse_yul_block_unchecked()?;
+        // Not `eat_keyword`, since `else` should not be suggested after every `if` block.
+        if self.token.is_keyword(kw::Else) {
+            self.bump();
+            self.dcx()
+                .err("Yul `if` statements have no `else` branch")
+                .span(self.prev_token.span)
+                .help("use a `switch` statement instead")
+                .emit();
+            // Skip the `else` block, if any; `else if` is parsed as a separate `if` statement.
+            if self.token.is_open_delim(Delimiter::Brace) {
+                let _ = self.parse_yul_block_unchecked()?;
+            }
+        }
         Ok(StmtKind::If(cond, body))
     }
 
@@ -234,14 +255,50 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
 
     /// Parses a Yul expression.
     fn parse_yul_expr(&mut self) -> PResult<'sess, Expr<'ast>> {
-        self.parse_spanned(Self::parse_yul_expr_kind).map(|(span, kind)| Expr { span, kind })
+        let expr = self.parse_yul_expr_no_binop()?;
+        if self.token.kind.as_binop().is_some() {
+            self.recover_yul_binop(&expr)?;
+        }
+        Ok(expr)
+    }
+
+    fn parse_yul_expr_no_binop(&mut self) -> PResult<'sess, Expr<'ast>> {
+        self.with_depth("expression", |this| this.parse_spanned(Self::parse_yul_expr_kind))
+            .map(|(span, kind)| Expr { span, kind })
+    }
+
+    /// Recovers from Solidity binary operators after a Yul expression, such as `i < n`.
+    ///
+    /// The operators and their operands are skipped, and the left-hand side is kept.
+    fn recover_yul_binop(&mut self, lhs: &Expr<'_>) -> PResult<'sess, ()> {
+        let op_span = self.token.span;
+        let mut ops = Vec::new();
+        let mut rhs = lhs.span;
+        while let Some(op) = self.token.kind.as_binop() {
+            ops.push(op);
+            self.bump();
+            rhs = self.parse_yul_expr_no_binop()?.span;
+        }
+        let span = lhs.span.to(rhs);
+        let mut err = self.dcx().err("Yul does not have binary operators").span(span);
+        err = err.span_label(op_span, "not allowed in Yul");
+        let sm = self.sess.source_map();
+        let call = match (&ops[..], sm.span_to_snippet(lhs.span), sm.span_to_snippet(rhs)) {
+            (&[op], Ok(a), Ok(b)) => yul_builtin_for(op, &a, &b),
+            _ => None,
+        };
+        err = match call {
+            Some(call) => err.help(format!("use the builtin function instead: `{call}`")),
+            None => err.help("use builtin functions such as `add` and `lt` instead"),
+        };
+        err.emit();
+        Ok(())
     }
 
     /// Parses a Yul expression kind.
     fn parse_yul_expr_kind(&mut self) -> PResult<'sess, ExprKind<'ast>> {
         if self.check_lit() {
-            // NOTE: We can't `expect_no_subdenomination` because they're valid variable names.
-            self.parse_lit().map(ExprKind::Lit)
+            self.parse_yul_lit_expr_kind(true)
         } else if self.check_path() {
             let path = self.parse_path_any()?;
             if self.token.is_open_delim(Delimiter::Parenthesis) {
@@ -257,13 +314,91 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
     }
 
+    /// Parses a Yul literal expression kind.
+    ///
+    /// String literals used as values must fit in 32 bytes. This does not apply to literal
+    /// arguments of builtins, such as object names, which are not values.
+    fn parse_yul_lit_expr_kind(&mut self, is_value: bool) -> PResult<'sess, ExprKind<'ast>> {
+        // NOTE: We can't `expect_no_subdenomination` because they're valid variable names.
+        let lit = self.parse_lit()?;
+        match &lit.kind {
+            LitKind::Str(_, value) if is_value && value.len() > 32 => {
+                let msg = format!("string literal too long ({} > 32)", value.len());
+                self.dcx().err(msg).code(error_code!(3069)).span(lit.span).emit();
+            }
+            LitKind::Number(value) if value.bits() > 256 => {
+                let msg = "number literal too large (> 256 bits)";
+                self.dcx().err(msg).code(error_code!(6708)).span(lit.span).emit();
+            }
+            _ => {}
+        }
+        let ty = if self.eat(&TokenKind::Colon) { Some(self.parse_ident()?) } else { None };
+        Ok(ExprKind::Lit(lit, ty))
+    }
+
     /// Parses a Yul function call expression with the given name.
     fn parse_yul_expr_call_with(&mut self, name: Ident) -> PResult<'sess, ExprCall<'ast>> {
         if !name.is_yul_evm_builtin() && name.is_reserved(true) {
             self.expected_ident_found_other(name.into(), false).unwrap_err().emit();
         }
-        let arguments = self.parse_paren_comma_seq(true, Self::parse_yul_expr)?;
-        Ok(ExprCall { name, arguments })
+        let mut index = 0;
+        let arguments = self.parse_paren_comma_seq(true, |this| {
+            let is_literal_arg = is_literal_argument(name, index);
+            index += 1;
+            if is_literal_arg && this.check_lit() {
+                this.parse_spanned(|this| this.parse_yul_lit_expr_kind(false))
+                    .map(|(span, kind)| Expr { span, kind })
+            } else {
+                this.parse_yul_expr()
+            }
+        })?;
+        let call = ExprCall { name, arguments };
+        if let Some((inputs, _)) = call.verbatim_io() {
+            self.check_verbatim_args(&call, inputs);
+        }
+        self.check_object_access_args(&call);
+        Ok(call)
+    }
+
+    /// Checks the arguments of the `datasize`, `dataoffset`, and `datacopy` object access
+    /// builtins.
+    ///
+    /// Reference: <https://docs.soliditylang.org/en/latest/yul.html#datasize-dataoffset-datacopy>
+    fn check_object_access_args(&mut self, call: &ExprCall<'_>) {
+        let ExprCall { name, arguments } = call;
+        match name.name {
+            sym::datasize | sym::dataoffset if call.data_ref().is_none() => {
+                let msg = format!("`{name}` expects a single string literal argument");
+                self.dcx().err(msg).span(name.span).emit();
+            }
+            sym::datacopy if arguments.len() != 3 => {
+                let msg = format!("`datacopy` expects 3 arguments, but got {}", arguments.len());
+                self.dcx().err(msg).span(name.span).emit();
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks the arguments of a `verbatim_<N>i_<M>o` call: the bytecode string literal, followed
+    /// by `N` inputs.
+    fn check_verbatim_args(&mut self, call: &ExprCall<'_>, inputs: u8) {
+        let expected = inputs as usize + 1;
+        if call.arguments.len() != expected {
+            let s = if expected == 1 { "" } else { "s" };
+            let msg = format!(
+                "`{}` expects {expected} argument{s}, but got {}",
+                call.name,
+                call.arguments.len()
+            );
+            let span = call.name.span.to(self.prev_token.span);
+            self.dcx().err(msg).span(span).emit();
+        }
+        if let Some(code) = call.arguments.first() {
+            if !matches!(code.kind, ExprKind::Lit(Lit { kind: LitKind::Str(..), .. }, _)) {
+                let msg = "the first argument of `verbatim` must be a string literal";
+                self.dcx().err(msg).span(code.span).emit();
+            }
+        }
     }
 
     /// Expects a single identifier path and returns the identifier.
@@ -289,3 +424,303 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
     }
 }
+
+/// Returns `true` if the argument at `index` of the builtin `name` is a literal argument, such as
+/// the object name of `datasize` or the bytecode of `verbatim`.
+///
+/// Reference: <https://docs.soliditylang.org/en/latest/yul.html#specification-of-yul>
+fn is_literal_argument(name: Ident, index: usize) -> bool {
+    index == 0
+        && (matches!(name.name, sym::datasize | sym::dataoffset)
+            || parse_verbatim_name(name.as_str()).is_some())
+}
+
+/// Returns the Yul builtin call equivalent to the binary operation `a op b`, if any.
+fn yul_builtin_for(op: BinOpKind, a: &str, b: &str) -> Option<String> {
+    let (f, a, b) = match op {
+        BinOpKind::Lt => ("lt", a, b),
+        BinOpKind::Gt => ("gt", a, b),
+        BinOpKind::Le => return Some(format!("iszero(gt({a}, {b}))")),
+        BinOpKind::Ge => return Some(format!("iszero(lt({a}, {b}))")),
+        BinOpKind::Eq => ("eq", a, b),
+        BinOpKind::Ne => return Some(format!("iszero(eq({a}, {b}))")),
+        // Shifts take the shift amount first.
+        BinOpKind::Shl => ("shl", b, a),
+        BinOpKind::Shr => ("shr", b, a),
+        BinOpKind::Sar => ("sar", b, a),
+        BinOpKind::BitAnd => ("and", a, b),
+        BinOpKind::BitOr => ("or", a, b),
+        BinOpKind::BitXor => ("xor", a, b),
+        BinOpKind::Add => ("add", a, b),
+        BinOpKind::Sub => ("sub", a, b),
+        BinOpKind::Pow => ("exp", a, b),
+        BinOpKind::Mul => ("mul", a, b),
+        BinOpKind::Div => ("div", a, b),
+        BinOpKind::Rem => ("mod", a, b),
+        // `and` and `or` are bitwise, so they are not equivalent to `&&` and `||`.
+        BinOpKind::And | BinOpKind::Or => return None,
+    };
+    Some(format!("{f}({a}, {b})"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solar_ast::Arena;
+    use solar_interface::{source_map::FileName, Result, Session};
+
+    /// Parses `src` as a Yul block, asserting that no errors were emitted.
+    #[track_caller]
+    fn with_yul_block(src: &str, f: impl FnOnce(&Block<'_>)) {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| -> Result {
+            let arena = Arena::new();
+            let filename = FileName::Custom("test".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, filename, src.into())?;
+            let block = parser.parse_yul_block().map_err(|e| e.emit())?;
+            sess.dcx.has_errors()?;
+            f(&block);
+            Ok(())
+        })
+        .unwrap_or_else(|_| panic!("src: {src:?}"));
+    }
+
+    #[test]
+    fn var_decls_and_assignments() {
+        let src = "{ let a, b := f(1) let c a := 1 a, b := g() }";
+        with_yul_block(src, |block| {
+            let [decl_multi, decl_bare, assign_single, assign_multi] = &block[..] else {
+                panic!("unexpected statements: {block:#?}")
+            };
+
+            let StmtKind::VarDecl(names, Some(init)) = &decl_multi.kind else {
+                panic!("{decl_multi:#?}")
+            };
+            let names = names.iter().map(|name| name.as_str()).collect::<Vec<_>>();
+            assert_eq!(names, ["a", "b"]);
+            let ExprKind::Call(call) = &init.kind else { panic!("{init:#?}") };
+            assert_eq!(call.name.as_str(), "f");
+            assert_eq!(call.arguments.len(), 1);
+
+            let StmtKind::VarDecl(names, None) = &decl_bare.kind else { panic!("{decl_bare:#?}") };
+            assert_eq!(names.len(), 1);
+            assert_eq!(names[0].as_str(), "c");
+
+            let StmtKind::AssignSingle(path, value) = &assign_single.kind else {
+                panic!("{assign_single:#?}")
+            };
+            assert_eq!(path.last().as_str(), "a");
+            assert!(matches!(value.kind, ExprKind::Lit(_, None)), "{value:#?}");
+
+            let StmtKind::AssignMulti(paths, call) = &assign_multi.kind else {
+                panic!("{assign_multi:#?}")
+            };
+            let paths = paths.iter().map(|path| path.last().as_str()).collect::<Vec<_>>();
+            assert_eq!(paths, ["a", "b"]);
+            assert_eq!(call.name.as_str(), "g");
+        });
+    }
+
+    #[test]
+    fn expr_stmts() {
+        let src = "{
+            pop(mload(0))
+            mstore(0, 1)
+            sstore(0, 1) sstore(1, 2)
+        }";
+        with_yul_block(src, |block| {
+            let names = block
+                .iter()
+                .map(|stmt| {
+                    let StmtKind::Expr(call) = &stmt.kind else { panic!("{stmt:#?}") };
+                    call.name.as_str()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(names, ["pop", "mstore", "sstore", "sstore"]);
+
+            let StmtKind::Expr(pop) = &block[0].kind else { unreachable!() };
+            let [Expr { kind: ExprKind::Call(mload), .. }] = &pop.arguments[..] else {
+                panic!("{pop:#?}")
+            };
+            assert_eq!(mload.name.as_str(), "mload");
+        });
+    }
+
+    #[test]
+    fn if_stmt() {
+        with_yul_block("{ if lt(x, 2) { x := 3 } if x {} }", |block| {
+            let [lt, bare] = &block[..] else { panic!("{block:#?}") };
+            let StmtKind::If(cond, body) = &lt.kind else { panic!("{lt:#?}") };
+            let ExprKind::Call(call) = &cond.kind else { panic!("{cond:#?}") };
+            assert_eq!(call.name.as_str(), "lt");
+            assert_eq!(body.len(), 1);
+            let StmtKind::If(cond, body) = &bare.kind else { panic!("{bare:#?}") };
+            assert!(matches!(cond.kind, ExprKind::Path(..)), "{cond:#?}");
+            assert!(body.is_empty());
+        });
+    }
+
+    #[test]
+    fn slot_offset_length() {
+        let src = "{ sstore(x.slot, 1) let o := x.offset b.offset := add(b.offset, b.length) }";
+        with_yul_block(src, |block| {
+            let path_str = |path: &PathSlice| {
+                path.segments().iter().map(|s| s.to_string()).collect::<Vec<_>>()
+            };
+            let path_of = |expr: &Expr<'_>| {
+                let ExprKind::Path(path) = &expr.kind else { panic!("{expr:#?}") };
+                path_str(path)
+            };
+            let [sstore, decl, assign] = &block[..] else { panic!("{block:#?}") };
+
+            let StmtKind::Expr(ExprCall { name, arguments }) = &sstore.kind else {
+                panic!("{sstore:#?}")
+            };
+            assert_eq!(name.as_str(), "sstore");
+            assert_eq!(path_of(&arguments[0]), ["x", "slot"]);
+
+            let StmtKind::VarDecl(_, Some(init)) = &decl.kind else { panic!("{decl:#?}") };
+            assert_eq!(path_of(init), ["x", "offset"]);
+
+            let StmtKind::AssignSingle(path, value) = &assign.kind else { panic!("{assign:#?}") };
+            assert_eq!(path_str(path), ["b", "offset"]);
+            let ExprKind::Call(call) = &value.kind else { panic!("{value:#?}") };
+            let args = call.arguments.iter().map(path_of).collect::<Vec<_>>();
+            assert_eq!(args, [["b", "offset"], ["b", "length"]]);
+        });
+    }
+
+    #[test]
+    fn literals() {
+        let src =
+            r#"{ let a := 0x1234:u256 let b := true let c := false:bool let d := "abc":u256 }"#;
+        with_yul_block(src, |block| {
+            let lits = block
+                .iter()
+                .map(|stmt| {
+                    let StmtKind::VarDecl(_, Some(Expr { kind: ExprKind::Lit(lit, ty), .. })) =
+                        &stmt.kind
+                    else {
+                        panic!("{stmt:#?}")
+                    };
+                    (lit.kind.description(), ty.map(|ty| ty.to_string()))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                lits,
+                [
+                    ("number", Some("u256".to_string())),
+                    ("boolean", None),
+                    ("boolean", Some("bool".to_string())),
+                    ("string", Some("u256".to_string())),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn verbatim() {
+        let src = r#"{ let x := verbatim_2i_1o(hex"600202", a, b) verbatim_0i_0o("\x00") }"#;
+        with_yul_block(src, |block| {
+            let StmtKind::VarDecl(_, Some(Expr { kind: ExprKind::Call(call), .. })) =
+                &block[0].kind
+            else {
+                panic!("{block:#?}")
+            };
+            assert_eq!(call.verbatim_io(), Some((2, 1)));
+            assert_eq!(call.arguments.len(), 3);
+            let StmtKind::Expr(call) = &block[1].kind else { panic!("{block:#?}") };
+            assert_eq!(call.verbatim_io(), Some((0, 0)));
+        });
+        with_yul_block("{ verbatim(1) verbatim_1i(2) }", |block| {
+            for stmt in block.iter() {
+                let StmtKind::Expr(call) = &stmt.kind else { panic!("{stmt:#?}") };
+                assert_eq!(call.verbatim_io(), None);
+            }
+        });
+    }
+
+    #[test]
+    fn objects() {
+        let src = r#"object "A" {
+            code { sstore(0, 1) }
+            object "A_deployed" {
+                code { mstore(0, 1) }
+                data "inner" hex"1234"
+            }
+            data "meta" "abc"
+        }"#;
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| -> Result {
+            let arena = Arena::new();
+            let filename = FileName::Custom("test".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, filename, src.into())?;
+            let object = parser.parse_yul_file_object().map_err(|e| e.emit())?;
+            sess.dcx.has_errors()?;
+
+            assert_eq!(object.name.value.as_str(), "A");
+            assert_eq!(object.code.code.len(), 1);
+            let [data] = &object.data[..] else { panic!("{:#?}", object.data) };
+            assert_eq!(data.name.value.as_str(), "meta");
+
+            let [child] = &object.children[..] else { panic!("{:#?}", object.children) };
+            assert_eq!(child.name.value.as_str(), "A_deployed");
+            assert_eq!(child.code.code.len(), 1);
+            assert!(child.children.is_empty());
+            let [data] = &child.data[..] else { panic!("{:#?}", child.data) };
+            assert_eq!(data.name.value.as_str(), "inner");
+            assert!(matches!(data.data.kind, LitKind::Str(StrKind::Hex, _)), "{data:#?}");
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn builtins_named_like_keywords() {
+        let src =
+            "{ let a := address() let b := byte(0, a) codecopy(0, 0, codesize()) return(0, 0) }";
+        with_yul_block(src, |block| {
+            let names = block
+                .iter()
+                .map(|stmt| {
+                    let call = match &stmt.kind {
+                        StmtKind::VarDecl(_, Some(Expr { kind: ExprKind::Call(call), .. })) => call,
+                        StmtKind::Expr(call) => call,
+                        _ => panic!("{stmt:#?}"),
+                    };
+                    call.name.as_str()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(names, ["address", "byte", "codecopy", "return"]);
+        });
+    }
+
+    #[test]
+    fn object_data_refs() {
+        let src = r#"object "A" {
+            code {
+                datacopy(0, dataoffset("runtime"), datasize("runtime"))
+                return(0, add(datasize("meta"), 1))
+            }
+            object "runtime" {
+                code { mstore(0, 1) }
+            }
+            data "meta" "abc"
+        }"#;
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| -> Result {
+            let arena = Arena::new();
+            let filename = FileName::Custom("test".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, filename, src.into())?;
+            let object = arena.alloc(parser.parse_yul_file_object().map_err(|e| e.emit())?);
+            sess.dcx.has_errors()?;
+
+            let refs = object.code.data_refs();
+            let refs = refs.iter().map(|r| r.value.as_str()).collect::<Vec<_>>();
+            assert_eq!(refs, ["runtime", "runtime", "meta"]);
+            assert!(object.children[0].code.data_refs().is_empty());
+            Ok(())
+        })
+        .unwrap();
+    }
+}
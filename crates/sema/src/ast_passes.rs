@@ -1,8 +1,9 @@
 //! AST-related passes.
 
+use alloy_primitives::U256;
 use solar_ast::{self as ast, visit::Visit};
 use solar_data_structures::Never;
-use solar_interface::{diagnostics::DiagCtxt, sym, Session, Span};
+use solar_interface::{diagnostics::DiagCtxt, error_code, kw, sym, BytePos, Session, Span, Symbol};
 use std::ops::ControlFlow;
 
 #[instrument(name = "ast_passes", level = "debug", skip_all)]
@@ -14,9 +15,18 @@ pub(crate) fn run(sess: &Session, ast: &ast::SourceUnit<'_>) {
 #[instrument(name = "validate", level = "debug", skip_all)]
 pub fn validate(sess: &Session, ast: &ast::SourceUnit<'_>) {
     let mut validator = AstValidator::new(sess);
+    validator.check_licenses(ast);
+    if sess.opts.warn_conflicting_pragmas {
+        validator.check_version_pragmas(ast);
+    }
     validator.visit_source_unit(ast);
 }
 
+/// Built-in functions whose calls are checked for the number of arguments, with the minimum and
+/// maximum number of arguments.
+const BUILTIN_ARG_COUNTS: [(Symbol, usize, usize); 3] =
+    [(sym::assert, 1, 1), (sym::require, 1, 2), (kw::Revert, 0, 1)];
+
 /// AST validator.
 struct AstValidator<'sess, 'ast> {
     item_span: Span,
@@ -51,6 +61,58 @@ impl<'sess> AstValidator<'sess, '_> {
         self.loop_depth != 0
     }
 
+    fn check_licenses(&self, unit: &ast::SourceUnit<'_>) {
+        if let [first, rest @ ..] = &unit.licenses[..] {
+            if let Some(other) = rest.iter().find(|license| license.value != first.value) {
+                let msg = "multiple SPDX license identifiers found in source file";
+                self.dcx()
+                    .err(msg)
+                    .code(error_code!(3716))
+                    .span(other.span)
+                    .span_note(first.span, "first license identifier found here")
+                    .help("use `AND` or `OR` to combine multiple licenses")
+                    .emit();
+            }
+        }
+    }
+
+    /// Warns about pairs of `solidity` version pragmas that cannot be satisfied by any single
+    /// compiler version, such as `^0.7.0` and `^0.8.0`.
+    ///
+    /// Only enabled with `--warn-conflicting-pragmas`.
+    fn check_version_pragmas(&self, unit: &ast::SourceUnit<'_>) {
+        let pragmas = unit
+            .items
+            .iter()
+            .filter_map(|item| match &item.kind {
+                ast::ItemKind::Pragma(ast::PragmaDirective {
+                    tokens: ast::PragmaTokens::Version(name, req),
+                }) if name.name == sym::solidity => Some((item.span, req)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for (i, &(span, req)) in pragmas.iter().enumerate() {
+            // Requirements that cannot be satisfied on their own are not conflicts.
+            if !version_reqs_compatible(req, req) {
+                continue;
+            }
+            let Some(&(prev_span, prev)) = pragmas[..i].iter().find(|(_, prev)| {
+                version_reqs_compatible(prev, prev) && !version_reqs_compatible(prev, req)
+            }) else {
+                continue;
+            };
+            self.dcx()
+                .warn("conflicting version pragmas")
+                .span(span)
+                .span_note(
+                    prev_span,
+                    format!("no compiler version satisfies both `{prev}` and `{req}`"),
+                )
+                .emit();
+            return;
+        }
+    }
+
     fn check_single_statement_variable_declaration(&self, stmt: &ast::Stmt<'_>) {
         if matches!(stmt.kind, ast::StmtKind::DeclSingle(..) | ast::StmtKind::DeclMulti(..)) {
             self.dcx()
@@ -61,39 +123,159 @@ impl<'sess> AstValidator<'sess, '_> {
         }
     }
 
-    fn check_underscores_in_number_literals(&self, lit: &ast::Lit) {
-        let ast::LitKind::Number(_) = lit.kind else {
+    /// Checks the number of arguments of calls to the built-in functions in
+    /// [`BUILTIN_ARG_COUNTS`], such as `require(cond, "msg")` or `require(cond, MyError())`.
+    fn check_builtin_call_args(&self, call: &ast::Expr<'_>) {
+        let ast::ExprKind::Call(callee, args) = &call.kind else { return };
+        let ast::ExprKind::Ident(name) = &callee.kind else { return };
+        let Some(&(_, min, max)) = BUILTIN_ARG_COUNTS.iter().find(|(s, ..)| *s == name.name) else {
             return;
         };
-        let literal_span = lit.span;
-        let literal_str = lit.symbol.as_str();
+        let n = args.len();
+        if (min..=max).contains(&n) {
+            return;
+        }
+        let expected = match (min, max) {
+            (min, max) if min == max => min.to_string(),
+            (min, max) if min + 1 == max => format!("{min} or {max}"),
+            (min, max) => format!("{min} to {max}"),
+        };
+        let msg = format!("wrong number of arguments to `{name}`: expected {expected}, found {n}");
+        self.dcx().err(msg).span(call.span).emit();
+    }
 
-        let error_help_msg = {
-            if literal_str.ends_with('_') {
-                Some("remove trailing underscores")
-            } else if literal_str.contains("__") {
-                Some("only 1 consecutive underscore `_` is allowed between digits")
-            } else if literal_str.contains("._") || literal_str.contains("_.") {
-                Some("remove underscores in front of the fraction part")
-            } else if literal_str.contains("_e") {
-                Some("remove underscores at the end of the mantissa")
-            } else if literal_str.contains("e_") {
-                Some("remove underscores in front of the exponent")
-            } else {
-                None
+    /// Checks for division and modulo by a constant zero, such as `1 / 0` or `x %= (2 - 2)`.
+    fn check_division_by_zero(&self, expr: &ast::Expr<'_>) {
+        let (op, rhs) = match &expr.kind {
+            ast::ExprKind::Binary(_, op, rhs) | ast::ExprKind::Assign(_, Some(op), rhs) => {
+                (op, rhs)
             }
+            _ => return,
+        };
+        let msg = match op.kind {
+            ast::BinOpKind::Div => "division by zero",
+            ast::BinOpKind::Rem => "modulo by zero",
+            _ => return,
         };
+        if fold_literal(rhs).is_some_and(|value| value.is_zero()) {
+            self.dcx().err(msg).code(error_code!(1211)).span(expr.span).emit();
+        }
+    }
 
-        if let Some(error_help_msg) = error_help_msg {
+    fn check_underscores_in_number_literals(&self, lit: &ast::Lit) {
+        let (ast::LitKind::Number(_) | ast::LitKind::Rational(_)) = lit.kind else {
+            return;
+        };
+        let literal_str = lit.symbol.as_str();
+        let is_hex = literal_str.starts_with("0x");
+
+        // Find the first misplaced underscore, returning its offset and the number of bytes to
+        // highlight.
+        let find = |pat: &str, offset: usize| {
+            literal_str.find(pat).map(|i| {
+                let start = i + offset;
+                let len = literal_str[start..].bytes().take_while(|&b| b == b'_').count();
+                (start, len)
+            })
+        };
+        let error = if literal_str.ends_with('_') {
+            let start = literal_str.trim_end_matches('_').len();
+            Some((start, literal_str.len() - start, "remove trailing underscores"))
+        } else if let Some((start, len)) = find("__", 0) {
+            Some((start, len, "only 1 consecutive underscore `_` is allowed between digits"))
+        } else if let Some((start, len)) = find("0x_", 2) {
+            Some((start, len, "remove underscores after the hex prefix"))
+        } else if let Some((start, len)) = find("_.", 0).or_else(|| find("._", 1)) {
+            Some((start, len, "remove underscores in front of the fraction part"))
+        } else if let Some((start, len)) = find("_e", 0).filter(|_| !is_hex) {
+            Some((start, len, "remove underscores at the end of the mantissa"))
+        } else if let Some((start, len)) = find("e_", 1).filter(|_| !is_hex) {
+            Some((start, len, "remove underscores in front of the exponent"))
+        } else {
+            None
+        };
+
+        if let Some((start, len, help)) = error {
+            let lo = lit.span.lo() + BytePos::from_usize(start);
+            let span = Span::new(lo, lo + BytePos::from_usize(len));
             self.dcx()
                 .err("invalid use of underscores in number literal")
-                .span(literal_span)
-                .help(error_help_msg)
+                .span(span)
+                .help(help)
                 .emit();
         }
     }
 }
 
+/// Folds an expression made up only of integer literals and arithmetic operators.
+///
+/// Returns `None` if the expression contains anything else, or if the evaluation overflows or
+/// divides by zero.
+fn fold_literal(expr: &ast::Expr<'_>) -> Option<U256> {
+    match &expr.kind {
+        ast::ExprKind::Lit(lit, None) => match &lit.kind {
+            ast::LitKind::Number(n) => {
+                let (_, limbs) = n.to_u64_digits();
+                U256::checked_from_limbs_slice(&limbs)
+            }
+            _ => None,
+        },
+        ast::ExprKind::Tuple([Some(inner)]) => fold_literal(inner),
+        ast::ExprKind::Binary(lhs, op, rhs) => {
+            let (l, r) = (fold_literal(lhs)?, fold_literal(rhs)?);
+            match op.kind {
+                ast::BinOpKind::Add => l.checked_add(r),
+                ast::BinOpKind::Sub => l.checked_sub(r),
+                ast::BinOpKind::Mul => l.checked_mul(r),
+                ast::BinOpKind::Div => l.checked_div(r),
+                ast::BinOpKind::Rem => l.checked_rem(r),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if there is a version that satisfies both requirements.
+///
+/// Every requirement component matches a range of versions whose lower bound is either `0.0.0`,
+/// or a version mentioned in a component, possibly with one of its numbers incremented. If the
+/// requirements have a common version, the smallest one is therefore one of these candidates.
+fn version_reqs_compatible<'ast>(a: &ast::SemverReq<'ast>, b: &ast::SemverReq<'ast>) -> bool {
+    let mentioned = [a, b]
+        .into_iter()
+        .flat_map(|req| req.dis.iter())
+        .flat_map(|con| con.components.iter())
+        .flat_map(|component| match &component.kind {
+            ast::SemverReqComponentKind::Op(_, v) => [Some(v), None],
+            ast::SemverReqComponentKind::Range(l, r) => [Some(l), Some(r)],
+        })
+        .flatten();
+    let number = |n: Option<ast::SemverVersionNumber>| match n {
+        Some(ast::SemverVersionNumber::Number(n)) => n,
+        Some(ast::SemverVersionNumber::Wildcard) | None => 0,
+    };
+    let mut candidates = vec![(0, 0, 0)];
+    for v in mentioned {
+        let (major, minor, patch) = (number(Some(v.major)), number(v.minor), number(v.patch));
+        candidates.extend([
+            (major, minor, patch),
+            (major, minor, patch.saturating_add(1)),
+            (major, minor.saturating_add(1), 0),
+            (major.saturating_add(1), 0, 0),
+        ]);
+    }
+    candidates.into_iter().any(|(major, minor, patch)| {
+        let version = ast::SemverVersion {
+            span: Span::DUMMY,
+            major: ast::SemverVersionNumber::Number(major),
+            minor: Some(ast::SemverVersionNumber::Number(minor)),
+            patch: Some(ast::SemverVersionNumber::Number(patch)),
+        };
+        a.matches(&version) && b.matches(&version)
+    })
+}
+
 impl<'ast> Visit<'ast> for AstValidator<'_, 'ast> {
     type BreakValue = Never;
 
@@ -131,30 +313,26 @@ impl<'ast> Visit<'ast> for AstValidator<'_, 'ast> {
         &mut self,
         pragma: &'ast ast::PragmaDirective<'ast>,
     ) -> ControlFlow<Self::BreakValue> {
-        match &pragma.tokens {
-            ast::PragmaTokens::Version(name, _version) => {
-                if name.name != sym::solidity {
-                    let msg = "only `solidity` is supported as a version pragma";
-                    self.dcx().err(msg).span(name.span).emit();
-                }
+        if let ast::PragmaTokens::Version(name, _version) = &pragma.tokens {
+            if name.name != sym::solidity {
+                let msg = "only `solidity` is supported as a version pragma";
+                self.dcx().err(msg).span(name.span).emit();
             }
-            ast::PragmaTokens::Custom(name, value) => {
-                let name = name.as_str();
-                let value = value.as_ref().map(ast::IdentOrStrLit::as_str);
-                match (name, value) {
-                    ("abicoder", Some("v1" | "v2")) => {}
-                    ("experimental", Some("ABIEncoderV2")) => {}
-                    ("experimental", Some("SMTChecker")) => {}
-                    ("experimental", Some("solidity")) => {
-                        let msg = "experimental solidity features are not supported";
-                        self.dcx().err(msg).span(self.item_span).emit();
-                    }
-                    _ => {
-                        self.dcx().err("unknown pragma").span(self.item_span).emit();
-                    }
+        }
+        match pragma.tokens.kind() {
+            ast::PragmaKind::Version | ast::PragmaKind::Abicoder(_) => {}
+            ast::PragmaKind::Experimental(feature) => match feature {
+                ast::ExperimentalFeature::AbiEncoderV2 | ast::ExperimentalFeature::SmtChecker => {}
+                ast::ExperimentalFeature::Solidity => {
+                    let msg = "experimental solidity features are not supported";
+                    self.dcx().err(msg).span(self.item_span).emit();
                 }
-            }
-            ast::PragmaTokens::Verbatim(_) => {
+                ast::ExperimentalFeature::Unknown(feature) => {
+                    let msg = format!("unknown experimental feature `{feature}`");
+                    self.dcx().warn(msg).span(self.item_span).emit();
+                }
+            },
+            ast::PragmaKind::Unknown => {
                 self.dcx().err("unknown pragma").span(self.item_span).emit();
             }
         }
@@ -314,6 +492,7 @@ impl<'ast> Visit<'ast> for AstValidator<'_, 'ast> {
                 } {
                     self.dcx()
                         .err("no visibility specified")
+                        .code(error_code!(4937))
                         .span(self.item_span)
                         .help(format!("add `{suggested_visibility}` to the declaration"))
                         .emit();
@@ -386,8 +565,13 @@ impl<'ast> Visit<'ast> for AstValidator<'_, 'ast> {
 
     fn visit_expr(&mut self, expr: &'ast ast::Expr<'ast>) -> ControlFlow<Self::BreakValue> {
         let ast::Expr { kind, .. } = expr;
-        if let ast::ExprKind::Lit(lit, _) = kind {
-            self.check_underscores_in_number_literals(lit);
+        match kind {
+            ast::ExprKind::Lit(lit, _) => self.check_underscores_in_number_literals(lit),
+            ast::ExprKind::Call(..) => self.check_builtin_call_args(expr),
+            ast::ExprKind::Binary(..) | ast::ExprKind::Assign(..) => {
+                self.check_division_by_zero(expr)
+            }
+            _ => {}
         }
         self.walk_expr(expr)
     }
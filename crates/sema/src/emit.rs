@@ -1,6 +1,7 @@
-use crate::ty::Gcx;
+use crate::{ty::Gcx, ParsedSources};
 use serde::Serialize;
-use solar_interface::config::CompilerOutput;
+use solar_interface::{config::CompilerOutput, Session};
+use solar_parse::{cst::CstNode, Lexer};
 use std::{
     collections::BTreeMap,
     io::{self, Write},
@@ -44,6 +45,11 @@ pub(crate) fn emit(gcx: Gcx<'_>) {
                     }
                     contract_output.hashes = Some(hashes);
                 }
+                // Emitted after parsing.
+                CompilerOutput::AstDebug
+                | CompilerOutput::ParseTree
+                | CompilerOutput::ParseTreeSpans
+                | CompilerOutput::Cst => {}
             }
         }
     }
@@ -57,6 +63,78 @@ pub(crate) fn emit(gcx: Gcx<'_>) {
     .map_err(|e| gcx.dcx().err(format!("failed to write to output: {e}")).emit());
 }
 
+/// Emits the `Debug` representation of the parsed ASTs.
+pub(crate) fn emit_ast_debug(sess: &Session, sources: &ParsedSources<'_>) {
+    let _ = (|| {
+        let out_path = sess.opts.out_dir.as_deref().map(|dir| dir.join("ast.txt"));
+        let mut writer = out_writer(out_path.as_deref())?;
+        for source in sources.iter() {
+            let Some(ast) = &source.ast else { continue };
+            writeln!(writer, "// {}", source.file.name.display())?;
+            if sess.opts.pretty_json {
+                writeln!(writer, "{ast:#?}")?;
+            } else {
+                writeln!(writer, "{ast:?}")?;
+            }
+        }
+        writer.flush()
+    })()
+    .map_err(|e| sess.dcx.err(format!("failed to write to output: {e}")).emit());
+}
+
+/// Emits the S-expression representation of the parsed ASTs.
+pub(crate) fn emit_parse_tree(sess: &Session, sources: &ParsedSources<'_>, spans: bool) {
+    let _ = (|| {
+        let out_path = sess.opts.out_dir.as_deref().map(|dir| dir.join("parse-tree.txt"));
+        let mut writer = out_writer(out_path.as_deref())?;
+        for source in sources.iter() {
+            let Some(ast) = &source.ast else { continue };
+            writeln!(writer, "; {}", source.file.name.display())?;
+            let spans = spans.then_some(source.file.start_pos);
+            write!(writer, "{}", solar_ast::sexpr::source_unit_to_sexpr(ast, spans))?;
+        }
+        writer.flush()
+    })()
+    .map_err(|e| sess.dcx.err(format!("failed to write to output: {e}")).emit());
+}
+
+/// Emits the concrete syntax tree of each parsed source file.
+pub(crate) fn emit_cst(sess: &Session, sources: &ParsedSources<'_>) {
+    let _ = (|| {
+        let out_path = sess.opts.out_dir.as_deref().map(|dir| dir.join("cst.txt"));
+        let mut writer = out_writer(out_path.as_deref())?;
+        for source in sources.iter() {
+            let file = &source.file;
+            let Ok(cst) = CstNode::from_source_file(sess, file) else { continue };
+            let mut dump = String::new();
+            cst.dump(file, &mut dump).map_err(io::Error::other)?;
+            writeln!(writer, "; {}", file.name.display())?;
+            write!(writer, "{dump}")?;
+        }
+        writer.flush()
+    })()
+    .map_err(|e| sess.dcx.err(format!("failed to write to output: {e}")).emit());
+}
+
+/// Prints the tokens of each loaded source file, one per line.
+pub(crate) fn emit_tokens(sess: &Session, sources: &ParsedSources<'_>, with_trivia: bool) {
+    let _ = (|| {
+        let mut writer = out_writer(None)?;
+        for source in sources.iter() {
+            let file = &source.file;
+            writeln!(writer, "; {}", file.name.display())?;
+            let lexer = Lexer::from_source_file(sess, file).keep_comments(with_trivia);
+            for token in lexer {
+                let lo = token.span.lo() - file.start_pos;
+                let hi = token.span.hi() - file.start_pos;
+                writeln!(writer, "{:?} {}..{}", token.kind, lo.0, hi.0)?;
+            }
+        }
+        writer.flush()
+    })()
+    .map_err(|e| sess.dcx.err(format!("failed to write to output: {e}")).emit());
+}
+
 fn out_writer(path: Option<&Path>) -> io::Result<impl io::Write> {
     let out: Box<dyn io::Write> = if let Some(path) = path {
         Box::new(std::fs::File::create(path)?)
@@ -11,7 +11,12 @@ extern crate tracing;
 
 use rayon::prelude::*;
 use solar_data_structures::{trustme, OnDrop};
-use solar_interface::{config::CompilerStage, Result, Session};
+use solar_interface::{
+    config::{CompilerOutput, CompilerStage, PrintKind},
+    diagnostics::Diag,
+    Result, Session,
+};
+use std::path::Path;
 use thread_local::ThreadLocal;
 use ty::Gcx;
 
@@ -38,6 +43,31 @@ mod emit;
 
 pub mod stats;
 
+/// Parses the given files and their imports, without emitting any diagnostics.
+///
+/// `f` is called with the parsed sources inside of the session globals, so that the symbols and
+/// spans contained in the ASTs can be accessed. Returns the result of `f` along with all the
+/// diagnostics that were emitted while loading and parsing the files.
+///
+/// Files that cannot be loaded are skipped, and reported as errors in the returned diagnostics.
+pub fn parse_files<P: AsRef<Path>, R: Send>(
+    sess: &Session,
+    paths: impl IntoIterator<Item = P> + Send,
+    f: impl FnOnce(&ParsedSources<'_>) -> R + Send,
+) -> (R, Vec<Diag>) {
+    sess.dcx.capture(|| {
+        sess.enter_parallel(|| {
+            let mut pcx = ParsingContext::new(sess);
+            for path in paths {
+                let _ = pcx.load_file(path.as_ref());
+            }
+            let arenas = ThreadLocal::<ast::Arena>::new();
+            let sources = pcx.parse(&arenas);
+            f(&sources)
+        })
+    })
+}
+
 /// Parses and semantically analyzes all the loaded sources, recursing into imports.
 pub fn parse_and_resolve(pcx: ParsingContext<'_>) -> Result<()> {
     let sess = pcx.sess;
@@ -48,6 +78,11 @@ pub fn parse_and_resolve(pcx: ParsingContext<'_>) -> Result<()> {
         return Err(sess.dcx.err(msg).note(note).emit());
     }
 
+    if let Some(mode) = sess.opts.dump_tokens {
+        emit::emit_tokens(sess, &pcx.sources, mode.is_with_trivia());
+        return sess.dcx.has_errors();
+    }
+
     let ast_arenas = OnDrop::new(ThreadLocal::<ast::Arena>::new(), |mut arenas| {
         debug!(asts_allocated = arenas.iter_mut().map(|a| a.allocated_bytes()).sum::<usize>());
         debug_span!("dropping_ast_arenas").in_scope(|| drop(arenas));
@@ -66,6 +101,34 @@ pub fn parse_and_resolve(pcx: ParsingContext<'_>) -> Result<()> {
         }
     }
 
+    if sess.opts.print.iter().any(PrintKind::is_ast_stats) {
+        stats::print_ast_counts(sources.asts(), "AST NODE COUNTS", "ast-stats");
+    }
+
+    if sess.opts.print.iter().any(PrintKind::is_symbol_stats) {
+        stats::print_symbol_stats("SYMBOL STATS", "symbol-stats");
+    }
+
+    if sess.opts.emit.contains(&CompilerOutput::AstDebug) {
+        emit::emit_ast_debug(sess, &sources);
+        sess.dcx.has_errors()?;
+    }
+
+    for (output, spans) in
+        [(CompilerOutput::ParseTree, false), (CompilerOutput::ParseTreeSpans, true)]
+    {
+        if sess.opts.emit.contains(&output) {
+            emit::emit_parse_tree(sess, &sources, spans);
+            sess.dcx.has_errors()?;
+        }
+    }
+
+    if sess.opts.emit.contains(&CompilerOutput::Cst) {
+        sess.dcx.has_errors()?;
+        emit::emit_cst(sess, &sources);
+        sess.dcx.has_errors()?;
+    }
+
     if sess.opts.language.is_yul() || sess.stop_after(CompilerStage::Parsed) {
         return Ok(());
     }
@@ -139,7 +202,15 @@ fn analysis(gcx: Gcx<'_>) -> Result<()> {
     typeck::check(gcx);
     gcx.sess.dcx.has_errors()?;
 
-    if !gcx.sess.opts.emit.is_empty() {
+    if gcx.sess.opts.emit.iter().any(|emit| {
+        !matches!(
+            emit,
+            CompilerOutput::AstDebug
+                | CompilerOutput::ParseTree
+                | CompilerOutput::ParseTreeSpans
+                | CompilerOutput::Cst
+        )
+    }) {
         emit::emit(gcx);
         gcx.sess.dcx.has_errors()?;
     }
@@ -189,3 +260,80 @@ fn match_file_name(name: &solar_interface::source_map::FileName, path: &str) ->
         solar_interface::source_map::FileName::Custom(name) => path == name,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solar_interface::diagnostics::Level;
+
+    #[test]
+    fn parse_files_programmatically() {
+        let dir = std::env::temp_dir().join(format!("solar-parse-files-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.sol"), "import \"./b.sol\"; contract A is B {}").unwrap();
+        std::fs::write(dir.join("b.sol"), "contract B { function f() {} }").unwrap();
+        std::fs::write(dir.join("c.sol"), "contract C {").unwrap();
+
+        let sess = Session::builder().with_test_emitter().build();
+        let paths = ["a.sol", "c.sol", "missing.sol"].map(|name| dir.join(name));
+        let ((len, names), diagnostics) = parse_files(&sess, paths, |sources| {
+            let items = sources.asts().flat_map(|ast| ast.items.iter());
+            let names = items.filter_map(|item| Some(item.name()?.to_string()));
+            (sources.len(), names.collect::<Vec<_>>())
+        });
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // `c.sol` failed to parse, and `missing.sol` could not be loaded.
+        assert_eq!(len, 3);
+        assert_eq!(names, ["A", "B"]);
+        assert_eq!(diagnostics.len(), 2, "{diagnostics:#?}");
+        assert!(diagnostics.iter().all(|diag| diag.level() == Level::Error));
+        assert_eq!(sess.dcx.err_count(), 2);
+    }
+
+    #[test]
+    fn reparse_cached_tokens() {
+        let dir = std::env::temp_dir().join(format!("solar-cached-tokens-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.sol"), "contract A {}").unwrap();
+
+        let sess = Session::builder().with_test_emitter().build();
+        let cache = solar_parse::TokenCache::from_session(&sess);
+        let mut names = Vec::new();
+        for hits in [0, 1] {
+            let (name, diagnostics) =
+                parse_files(&sess, [dir.join("a.sol")], |sources| sources[0].file.name.clone());
+            assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+            assert_eq!((cache.hits(), cache.misses()), (hits, 1));
+            names.push(name);
+        }
+
+        assert_eq!(names[0], names[1]);
+        assert!(sess.invalidate(&names[0]));
+        assert!(cache.is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn cyclic_imports() {
+        let dir = std::env::temp_dir().join(format!("solar-cyclic-imports-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.sol"), "import \"./b.sol\"; contract A {}").unwrap();
+        std::fs::write(dir.join("b.sol"), "import \"./a.sol\"; contract B {}").unwrap();
+
+        for threads in [1, 2] {
+            let sess = Session::builder().with_test_emitter().threads(threads).build();
+            let (files, diagnostics) = parse_files(&sess, [dir.join("a.sol")], |sources| {
+                let files = sources.iter().map(|source| {
+                    let name = source.file.name.display().to_string();
+                    let name = name.rsplit(['/', '\\']).next().unwrap().to_string();
+                    (name, source.imports.len())
+                });
+                files.collect::<Vec<_>>()
+            });
+            assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+            assert_eq!(files, [("a.sol".to_string(), 1), ("b.sol".to_string(), 1)]);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
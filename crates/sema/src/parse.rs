@@ -1,17 +1,18 @@
-use crate::hir::SourceId;
+use crate::{hir::SourceId, stats::FileTimes};
 use rayon::prelude::*;
 use solar_ast as ast;
 use solar_data_structures::{
     index::{Idx, IndexVec},
     map::FxHashSet,
+    sync::Lock,
 };
 use solar_interface::{
-    diagnostics::DiagCtxt,
-    source_map::{FileName, FileResolver, SourceFile},
+    diagnostics::{DiagBuilder, DiagCtxt, ErrorGuaranteed},
+    source_map::{FileName, FileResolver, ResolveError, SourceFile},
     Result, Session,
 };
-use solar_parse::{unescape, Lexer, Parser};
-use std::{borrow::Cow, fmt, path::Path, sync::Arc};
+use solar_parse::{unescape, Parser, TokenCache};
+use std::{borrow::Cow, fmt, path::Path, sync::Arc, time::Instant};
 use thread_local::ThreadLocal;
 
 pub struct ParsingContext<'sess> {
@@ -22,15 +23,22 @@ pub struct ParsingContext<'sess> {
     /// The loaded sources. Consumed once `parse` is called.
     /// The `'static` lifetime is a lie, as nothing borrowed is ever stored in this field.
     pub(crate) sources: ParsedSources<'static>,
+    /// The time spent lexing and parsing each file. Only recorded with `--time-passes`.
+    times: Option<Lock<Vec<FileTimes>>>,
 }
 
 impl<'sess> ParsingContext<'sess> {
     /// Creates a new parser context.
     pub fn new(sess: &'sess Session) -> Self {
+        let mut file_resolver = FileResolver::new(sess.source_map());
+        if let Some(max_file_size) = sess.opts.max_file_size {
+            file_resolver.set_max_file_size(max_file_size);
+        }
         Self {
             sess,
-            file_resolver: FileResolver::new(sess.source_map()),
+            file_resolver,
             sources: ParsedSources::new(),
+            times: sess.opts.time_passes.then(Default::default),
         }
     }
 
@@ -59,6 +67,9 @@ impl<'sess> ParsingContext<'sess> {
     }
 
     /// Loads a file into the context.
+    ///
+    /// If [`ignore_missing_files`](solar_interface::config::Opts::ignore_missing_files) is set,
+    /// files that cannot be read are skipped with a warning.
     #[instrument(level = "debug", skip_all)]
     pub fn load_file(&mut self, path: &Path) -> Result<()> {
         // Paths must be canonicalized before passing to the resolver.
@@ -73,14 +84,46 @@ impl<'sess> ParsingContext<'sess> {
             }
             Err(_) => path.to_path_buf(),
         };
-        let file = self
-            .file_resolver
-            .resolve_file(&path, None)
-            .map_err(|e| self.dcx().err(e.to_string()).emit())?;
+        let file = match self.file_resolver.resolve_file(&path, None) {
+            Ok(file) => file,
+            Err(e) if self.sess.opts.ignore_missing_files => {
+                self.dcx().warn(e.to_string()).note("skipping file").emit();
+                return Ok(());
+            }
+            Err(e) => return Err(self.resolve_error(e).emit()),
+        };
         self.add_file(file);
         Ok(())
     }
 
+    /// Creates an error for a file that could not be resolved.
+    ///
+    /// If the file was searched for in the import paths, they are listed in a note.
+    fn resolve_error(&self, e: ResolveError) -> DiagBuilder<'sess, ErrorGuaranteed> {
+        let mut diag = self.dcx().err(e.to_string());
+        match &e {
+            ResolveError::NotFound(path) => {
+                let searched =
+                    !(path.is_absolute() || path.starts_with("./") || path.starts_with("../"));
+                let import_paths = self
+                    .file_resolver
+                    .get_import_paths()
+                    .iter()
+                    .filter(|(map, _)| map.is_none())
+                    .map(|(_, path)| path.display().to_string())
+                    .collect::<Vec<_>>();
+                if searched && !import_paths.is_empty() {
+                    diag = diag.note(format!("searched in: {}", import_paths.join(", ")));
+                }
+            }
+            ResolveError::FileTooLarge(..) => {
+                diag = diag.help("use `--max-file-size` to raise the limit");
+            }
+            _ => {}
+        }
+        diag
+    }
+
     /// Adds a preloaded file to the resolver.
     pub fn add_file(&mut self, file: Arc<SourceFile>) {
         self.sources.add_file(file);
@@ -114,6 +157,9 @@ impl<'sess> ParsingContext<'sess> {
             );
         }
         sources.assert_unique();
+        if let Some(times) = self.times.take() {
+            crate::stats::print_time_passes(&times.into_inner(), "TIME PASSES", "time-passes");
+        }
         sources
     }
 
@@ -178,14 +224,20 @@ impl<'sess> ParsingContext<'sess> {
         file: &SourceFile,
         arena: &'ast ast::Arena,
     ) -> Option<ast::SourceUnit<'ast>> {
-        let lexer = Lexer::from_source_file(self.sess, file);
-        let mut parser = Parser::from_lexer(arena, lexer);
+        let start = self.times.is_some().then(Instant::now);
+        let tokens = TokenCache::from_session(self.sess).tokens(self.sess, file);
+        let lexed = start.map(|_| Instant::now());
+        let mut parser = Parser::new(self.sess, arena, tokens);
         let r = if self.sess.opts.language.is_yul() {
             let _file = parser.parse_yul_file_object().map_err(|e| e.emit());
             None
         } else {
             parser.parse_file().map_err(|e| e.emit()).ok()
         };
+        if let (Some(times), Some(start), Some(lexed)) = (&self.times, start, lexed) {
+            let name = file.name.display().to_string();
+            times.lock().push(FileTimes { name, lex: lexed - start, parse: lexed.elapsed() });
+        }
         trace!(allocated = arena.allocated_bytes(), used = arena.used_bytes(), "AST arena stats");
         r
     }
@@ -225,7 +277,7 @@ macro_rules! resolve_imports {
                 };
                 this.file_resolver
                     .resolve_file(path, parent.as_deref())
-                    .map_err(|e| this.dcx().err(e.to_string()).span(span).emit())
+                    .map_err(|e| this.resolve_error(e).span(span).emit())
                     .ok()
                     .map(|file| (id, file))
             })
@@ -3,7 +3,8 @@ use solar_data_structures::{
     map::{FxHashMap, FxHashSet},
     Never,
 };
-use std::ops::ControlFlow;
+use solar_interface::{InternerStats, Symbol};
+use std::{fmt::Write, ops::ControlFlow, time::Duration};
 
 struct NodeStats {
     count: usize,
@@ -43,6 +44,107 @@ pub fn print_ast_stats(ast: &ast::SourceUnit<'_>, title: &str, prefix: &str) {
     collector.print(title, prefix)
 }
 
+/// Prints the number of AST nodes of each kind, summed over all the given source units.
+pub fn print_ast_counts<'a, 'ast: 'a>(
+    asts: impl IntoIterator<Item = &'a ast::SourceUnit<'ast>>,
+    title: &str,
+    prefix: &str,
+) {
+    print!("{}", format_ast_counts(asts, title, prefix));
+}
+
+fn format_ast_counts<'a, 'ast: 'a>(
+    asts: impl IntoIterator<Item = &'a ast::SourceUnit<'ast>>,
+    title: &str,
+    prefix: &str,
+) -> String {
+    let mut counter = NodeCounter::default();
+    for ast in asts {
+        let _ = counter.visit_source_unit(ast);
+    }
+
+    // Most frequent first, then by name.
+    fn sorted<'a, T>(
+        map: &'a FxHashMap<&'static str, T>,
+        count: impl Fn(&T) -> usize,
+    ) -> Vec<(&'static str, usize, &'a T)> {
+        let mut v: Vec<_> = map.iter().map(|(&label, x)| (label, count(x), x)).collect();
+        v.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        v
+    }
+
+    let mut out = String::new();
+    let out = &mut out;
+    writeln!(out, "{prefix} {title}").unwrap();
+    writeln!(out, "{} {:<18}{:>14}", prefix, "Name", "Count").unwrap();
+    writeln!(out, "{prefix} --------------------------------").unwrap();
+    for (label, count, (_, variants)) in sorted(&counter.nodes, |&(count, _)| count) {
+        writeln!(out, "{} {:<18}{:>14}", prefix, label, to_readable_str(count)).unwrap();
+        for (label, count, _) in sorted(variants, |&count| count) {
+            writeln!(out, "{} - {:<16}{:>14}", prefix, label, to_readable_str(count)).unwrap();
+        }
+    }
+    writeln!(out, "{prefix}").unwrap();
+    std::mem::take(out)
+}
+
+pub fn print_symbol_stats(title: &str, prefix: &str) {
+    print!("{}", format_symbol_stats(Symbol::interner_stats(), title, prefix));
+}
+
+fn format_symbol_stats(stats: InternerStats, title: &str, prefix: &str) -> String {
+    let InternerStats { symbols, bytes, memory_usage } = stats;
+    let mut out = String::new();
+    let out = &mut out;
+    writeln!(out, "{prefix} {title}").unwrap();
+    writeln!(out, "{prefix} --------------------------------").unwrap();
+    writeln!(out, "{} {:<18}{:>14}", prefix, "Symbols", to_readable_str(symbols)).unwrap();
+    writeln!(out, "{} {:<18}{:>14}", prefix, "String bytes", to_readable_str(bytes)).unwrap();
+    writeln!(out, "{} {:<18}{:>14}", prefix, "Memory usage", to_readable_str(memory_usage))
+        .unwrap();
+    writeln!(out, "{prefix}").unwrap();
+    std::mem::take(out)
+}
+
+/// The time spent lexing and parsing a single file.
+#[derive(Clone, Debug)]
+pub(crate) struct FileTimes {
+    pub(crate) name: String,
+    pub(crate) lex: Duration,
+    pub(crate) parse: Duration,
+}
+
+pub(crate) fn print_time_passes(times: &[FileTimes], title: &str, prefix: &str) {
+    eprint!("{}", format_time_passes(times, title, prefix));
+}
+
+fn format_time_passes(times: &[FileTimes], title: &str, prefix: &str) -> String {
+    fn row(out: &mut String, prefix: &str, pass: &str, file: &str, time: Duration) {
+        let time = format!("{time:.3?}");
+        writeln!(out, "{prefix} {pass:<8}{file:<42}{time:>14}").unwrap();
+    }
+
+    let mut times = times.to_vec();
+    times.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut out = String::new();
+    let out = &mut out;
+    writeln!(out, "{prefix} {title}").unwrap();
+    writeln!(out, "{} {:<8}{:<42}{:>14}", prefix, "Pass", "File", "Time").unwrap();
+    writeln!(out, "{prefix} ----------------------------------------------------------------")
+        .unwrap();
+    for t in &times {
+        row(out, prefix, "lex", &t.name, t.lex);
+        row(out, prefix, "parse", &t.name, t.parse);
+    }
+    writeln!(out, "{prefix} ----------------------------------------------------------------")
+        .unwrap();
+    row(out, prefix, "lex", "Total", times.iter().map(|t| t.lex).sum());
+    row(out, prefix, "parse", "Total", times.iter().map(|t| t.parse).sum());
+    writeln!(out, "{prefix}").unwrap();
+    std::mem::take(out)
+}
+
 impl StatCollector {
     // Record a top-level node.
     fn record<T: ?Sized>(&mut self, label: &'static str, id: Option<ItemId>, val: &T) {
@@ -555,6 +657,291 @@ impl<'ast> Visit<'ast> for StatCollector {
     }
 }
 
+/// Counts every AST node by kind, and by variant for enums.
+///
+/// Unlike [`StatCollector`], which only records nodes that take up their own allocation, this
+/// walks the whole tree.
+#[derive(Default)]
+struct NodeCounter {
+    nodes: FxHashMap<&'static str, (usize, FxHashMap<&'static str, usize>)>,
+}
+
+impl NodeCounter {
+    fn record<T: ?Sized>(&mut self, label: &'static str, _id: Option<ItemId>, _val: &T) {
+        self.nodes.entry(label).or_default().0 += 1;
+    }
+
+    // Same signature as `StatCollector::record_variant`, for use in `record_variants!`.
+    fn record_variant<T: ?Sized>(
+        &mut self,
+        label: &'static str,
+        variant: &'static str,
+        _id: Option<ItemId>,
+        _val: &T,
+    ) {
+        let (count, variants) = self.nodes.entry(label).or_default();
+        *count += 1;
+        *variants.entry(variant).or_default() += 1;
+    }
+}
+
+impl<'ast> Visit<'ast> for NodeCounter {
+    type BreakValue = Never;
+
+    fn visit_source_unit(
+        &mut self,
+        source_unit: &ast::SourceUnit<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("SourceUnit", None, source_unit);
+        self.walk_source_unit(source_unit)
+    }
+
+    fn visit_item(&mut self, item: &'ast ast::Item<'ast>) -> ControlFlow<Self::BreakValue> {
+        record_variants!(
+            (self, item, item.kind, None, ast, Item, ItemKind),
+            [Pragma, Import, Using, Contract, Function, Variable, Struct, Enum, Udvt, Error, Event]
+        );
+        self.walk_item(item)
+    }
+
+    fn visit_pragma_directive(
+        &mut self,
+        pragma: &'ast ast::PragmaDirective<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("PragmaDirective", None, pragma);
+        self.walk_pragma_directive(pragma)
+    }
+
+    fn visit_import_directive(
+        &mut self,
+        import: &'ast ast::ImportDirective<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("ImportDirective", None, import);
+        self.walk_import_directive(import)
+    }
+
+    fn visit_using_directive(
+        &mut self,
+        using: &'ast ast::UsingDirective<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("UsingDirective", None, using);
+        self.walk_using_directive(using)
+    }
+
+    fn visit_item_contract(
+        &mut self,
+        contract: &'ast ast::ItemContract<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        record_variants!(
+            (self, contract, contract.kind, None, ast, ItemContract, ContractKind),
+            [Contract, AbstractContract, Interface, Library]
+        );
+        self.walk_item_contract(contract)
+    }
+
+    fn visit_item_function(
+        &mut self,
+        function: &'ast ast::ItemFunction<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        record_variants!(
+            (self, function, function.kind, None, ast, ItemFunction, FunctionKind),
+            [Constructor, Function, Fallback, Receive, Modifier]
+        );
+        self.walk_item_function(function)
+    }
+
+    fn visit_item_struct(
+        &mut self,
+        strukt: &'ast ast::ItemStruct<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("ItemStruct", None, strukt);
+        self.walk_item_struct(strukt)
+    }
+
+    fn visit_item_enum(
+        &mut self,
+        enum_: &'ast ast::ItemEnum<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("ItemEnum", None, enum_);
+        self.walk_item_enum(enum_)
+    }
+
+    fn visit_item_udvt(
+        &mut self,
+        udvt: &'ast ast::ItemUdvt<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("ItemUdvt", None, udvt);
+        self.walk_item_udvt(udvt)
+    }
+
+    fn visit_item_error(
+        &mut self,
+        error: &'ast ast::ItemError<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("ItemError", None, error);
+        self.walk_item_error(error)
+    }
+
+    fn visit_item_event(
+        &mut self,
+        event: &'ast ast::ItemEvent<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("ItemEvent", None, event);
+        self.walk_item_event(event)
+    }
+
+    fn visit_variable_definition(
+        &mut self,
+        var: &'ast ast::VariableDefinition<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("VariableDefinition", None, var);
+        self.walk_variable_definition(var)
+    }
+
+    fn visit_ty(&mut self, ty: &'ast ast::Type<'ast>) -> ControlFlow<Self::BreakValue> {
+        record_variants!(
+            (self, ty, ty.kind, None, ast, Type, TypeKind),
+            [Elementary, Array, Function, Mapping, Custom]
+        );
+        self.walk_ty(ty)
+    }
+
+    fn visit_modifier(
+        &mut self,
+        modifier: &'ast ast::Modifier<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("Modifier", None, modifier);
+        self.walk_modifier(modifier)
+    }
+
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt<'ast>) -> ControlFlow<Self::BreakValue> {
+        record_variants!(
+            (self, stmt, stmt.kind, None, ast, Stmt, StmtKind),
+            [
+                Assembly,
+                DeclSingle,
+                DeclMulti,
+                Block,
+                Break,
+                Continue,
+                DoWhile,
+                Emit,
+                Expr,
+                For,
+                If,
+                Return,
+                Revert,
+                Try,
+                UncheckedBlock,
+                While,
+                Placeholder
+            ]
+        );
+        self.walk_stmt(stmt)
+    }
+
+    fn visit_try_catch_clause(
+        &mut self,
+        catch: &'ast ast::TryCatchClause<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("TryCatchClause", None, catch);
+        self.walk_try_catch_clause(catch)
+    }
+
+    fn visit_block(&mut self, block: &'ast ast::Block<'ast>) -> ControlFlow<Self::BreakValue> {
+        self.record("Block", None, block);
+        self.walk_block(block)
+    }
+
+    fn visit_expr(&mut self, expr: &'ast ast::Expr<'ast>) -> ControlFlow<Self::BreakValue> {
+        record_variants!(
+            (self, expr, expr.kind, None, ast, Expr, ExprKind),
+            [
+                Array,
+                Assign,
+                Binary,
+                Call,
+                CallOptions,
+                Delete,
+                Ident,
+                Index,
+                Lit,
+                Member,
+                New,
+                Payable,
+                Ternary,
+                Tuple,
+                TypeCall,
+                Type,
+                Unary
+            ]
+        );
+        self.walk_expr(expr)
+    }
+
+    fn visit_lit(&mut self, lit: &'ast ast::Lit) -> ControlFlow<Self::BreakValue> {
+        self.record("Lit", None, lit);
+        self.walk_lit(lit)
+    }
+
+    fn visit_yul_stmt(&mut self, stmt: &'ast yul::Stmt<'ast>) -> ControlFlow<Self::BreakValue> {
+        record_variants!(
+            (self, stmt, stmt.kind, None, yul, YulStmt, StmtKind),
+            [
+                Block,
+                AssignSingle,
+                AssignMulti,
+                Expr,
+                If,
+                For,
+                Switch,
+                Leave,
+                Break,
+                Continue,
+                FunctionDef,
+                VarDecl
+            ]
+        );
+        self.walk_yul_stmt(stmt)
+    }
+
+    fn visit_yul_block(&mut self, block: &'ast yul::Block<'ast>) -> ControlFlow<Self::BreakValue> {
+        self.record("YulBlock", None, block);
+        self.walk_yul_block(block)
+    }
+
+    fn visit_yul_stmt_case(
+        &mut self,
+        case: &'ast yul::StmtSwitchCase<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("YulSwitchCase", None, case);
+        self.walk_yul_stmt_case(case)
+    }
+
+    fn visit_yul_expr(&mut self, expr: &'ast yul::Expr<'ast>) -> ControlFlow<Self::BreakValue> {
+        record_variants!((self, expr, expr.kind, None, yul, YulExpr, ExprKind), [Path, Call, Lit]);
+        self.walk_yul_expr(expr)
+    }
+
+    fn visit_doc_comment(
+        &mut self,
+        doc_comment: &'ast ast::DocComment,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.record("DocComment", None, doc_comment);
+        self.walk_doc_comment(doc_comment)
+    }
+
+    fn visit_path(&mut self, path: &'ast ast::PathSlice) -> ControlFlow<Self::BreakValue> {
+        self.record("Path", None, path);
+        self.walk_path(path)
+    }
+
+    fn visit_ident(&mut self, ident: &'ast ast::Ident) -> ControlFlow<Self::BreakValue> {
+        self.record("Ident", None, ident);
+        self.walk_ident(ident)
+    }
+}
+
 pub fn to_readable_str(mut val: usize) -> String {
     let mut groups = vec![];
     loop {
@@ -570,3 +957,131 @@ pub fn to_readable_str(mut val: usize) -> String {
     groups.reverse();
     groups.join("_")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_passes() {
+        let ms = Duration::from_millis;
+        let times = [
+            FileTimes { name: "b.sol".into(), lex: ms(3), parse: ms(4) },
+            FileTimes { name: "a.sol".into(), lex: ms(1), parse: ms(2) },
+        ];
+        let table = format_time_passes(&times, "TIME PASSES", "time-passes");
+        let rows = table
+            .lines()
+            .map(|line| line.split_whitespace().skip(1).collect::<Vec<_>>())
+            .filter(|row| matches!(row.first(), Some(&("lex" | "parse"))))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                ["lex", "a.sol", "1.000ms"],
+                ["parse", "a.sol", "2.000ms"],
+                ["lex", "b.sol", "3.000ms"],
+                ["parse", "b.sol", "4.000ms"],
+                ["lex", "Total", "4.000ms"],
+                ["parse", "Total", "6.000ms"],
+            ]
+        );
+    }
+
+    #[test]
+    fn ast_counts() {
+        use solar_interface::{source_map::FileName, Session};
+        use solar_parse::Parser;
+
+        let srcs = [
+            "contract A { function f() public { g(); } modifier m() { _; } }",
+            "contract B is A { uint x = 1 + 2; function g() internal {} }",
+        ];
+        let sess = Session::builder().with_test_emitter().build();
+        let table = sess.enter(|| {
+            let arena = ast::Arena::new();
+            let asts = srcs
+                .iter()
+                .enumerate()
+                .map(|(i, src)| {
+                    let name = FileName::Custom(i.to_string());
+                    let mut parser =
+                        Parser::from_source_code(&sess, &arena, name, src.to_string()).unwrap();
+                    parser.parse_file().map_err(|e| e.emit()).unwrap()
+                })
+                .collect::<Vec<_>>();
+            sess.dcx.has_errors().unwrap();
+            format_ast_counts(&asts, "AST NODE COUNTS", "ast-stats")
+        });
+        let expected = "\
+ast-stats AST NODE COUNTS
+ast-stats Name                       Count
+ast-stats --------------------------------
+ast-stats Ident                          8
+ast-stats Item                           6
+ast-stats - Function                     3
+ast-stats - Contract                     2
+ast-stats - Variable                     1
+ast-stats Expr                           5
+ast-stats - Lit                          2
+ast-stats - Binary                       1
+ast-stats - Call                         1
+ast-stats - Ident                        1
+ast-stats Block                          3
+ast-stats ItemFunction                   3
+ast-stats - Function                     2
+ast-stats - Modifier                     1
+ast-stats ItemContract                   2
+ast-stats - Contract                     2
+ast-stats Lit                            2
+ast-stats SourceUnit                     2
+ast-stats Stmt                           2
+ast-stats - Expr                         1
+ast-stats - Placeholder                  1
+ast-stats Modifier                       1
+ast-stats Path                           1
+ast-stats Type                           1
+ast-stats - Elementary                   1
+ast-stats VariableDefinition             1
+ast-stats
+";
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn symbol_stats() {
+        use solar_interface::{source_map::FileName, Session};
+        use solar_parse::Parser;
+
+        let sess = Session::builder().with_test_emitter().build();
+        let (before, after) = sess.enter(|| {
+            let before = Symbol::interner_stats();
+            let arena = ast::Arena::new();
+            let src = "contract Qwerty { uint zxcv; }".to_string();
+            let name = FileName::Custom("test".into());
+            let mut parser = Parser::from_source_code(&sess, &arena, name, src).unwrap();
+            parser.parse_file().map_err(|e| e.emit()).unwrap();
+            sess.dcx.has_errors().unwrap();
+            (before, Symbol::interner_stats())
+        });
+        assert_eq!(after.symbols - before.symbols, 2);
+        assert_eq!(after.bytes - before.bytes, "Qwertyzxcv".len());
+
+        let table = format_symbol_stats(after, "SYMBOL STATS", "symbol-stats");
+        let rows = table
+            .lines()
+            .skip(1) // title
+            .filter_map(|line| line.strip_prefix("symbol-stats "))
+            .filter_map(|row| row.rsplit_once(' '))
+            .map(|(label, value)| (label.trim(), value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                ("Symbols", &*to_readable_str(after.symbols)),
+                ("String bytes", &*to_readable_str(after.bytes)),
+                ("Memory usage", &*to_readable_str(after.memory_usage)),
+            ]
+        );
+    }
+}
@@ -22,6 +22,9 @@ pub use solar_parse as parse;
 #[doc(inline)]
 pub use solar_sema as sema;
 
+#[doc(inline)]
+pub use solar_sema::parse_files;
+
 #[cfg(feature = "cli")]
 #[doc(inline)]
 pub use solar_cli as cli;
@@ -0,0 +1,3 @@
+//@ compile-flags: --emit ast-debug --stop-after parsing
+
+contract A {}
@@ -0,0 +1,3 @@
+//@ compile-flags: --emit ast-debug --pretty --stop-after parsing
+
+contract A {}
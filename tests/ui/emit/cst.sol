@@ -0,0 +1,6 @@
+//@ compile-flags: --emit cst --stop-after parsing
+
+/// Doc.
+contract C {
+    uint x; // Trailing.
+}
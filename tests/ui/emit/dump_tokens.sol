@@ -0,0 +1,7 @@
+//@ compile-flags: --dump-tokens
+
+/// Doc.
+contract C {
+    uint x = 1 + 0x2; // Comment.
+    string s = "hi";
+}
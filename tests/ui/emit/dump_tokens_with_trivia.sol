@@ -0,0 +1,6 @@
+//@ compile-flags: --dump-tokens=with-trivia
+
+/// Doc.
+contract C {
+    uint x = 1; /* Comment. */
+}
@@ -0,0 +1,30 @@
+//@ compile-flags: --emit parse-tree --stop-after parsing
+
+pragma solidity ^0.8.0;
+
+/// Doc comments are not printed.
+contract C is B(1) {
+    uint256 public x = 1 + 2 * 3;
+    mapping(address => uint256[]) balances;
+
+    event E(address indexed from);
+
+    function f(uint256 a) public view m(a) returns (uint256 b) {
+        for (uint256 i; i < a; i++) {
+            b += balances[msg.sender][i];
+        }
+        emit E(msg.sender);
+        assembly {
+            let y := add(b, 1)
+        }
+        return b > 0 ? b : "zero".length;
+    }
+}
+
+abstract contract B {
+    constructor(uint256) {}
+
+    modifier m(uint256 x) virtual {
+        _;
+    }
+}
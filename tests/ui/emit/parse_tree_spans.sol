@@ -0,0 +1,7 @@
+//@ compile-flags: --emit parse-tree-spans --stop-after parsing
+
+contract C {
+    function f() {
+        x = 1;
+    }
+}
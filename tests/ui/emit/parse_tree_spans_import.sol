@@ -0,0 +1,6 @@
+//@ compile-flags: --emit parse-tree-spans --stop-after parsing
+// Spans are relative to the start of each file.
+
+import "./auxiliary/imported.sol";
+
+contract C is D {}
@@ -0,0 +1,8 @@
+//@ compile-flags: --ignore-missing-files ./missing.sol
+//@ error-in-other-file: file ./missing.sol not found
+
+contract C {
+    function f() public {
+        break; //~ ERROR: `break` outside of a loop
+    }
+}
@@ -0,0 +1,9 @@
+contract C {
+    string a = "\x41A";
+    string b = "ok \q"; //~ ERROR: unknown character escape
+    string c = "short \x1"; //~ ERROR: hex escape must be followed by 2 hex digits
+    string d = "tab	"; //~ ERROR: control characters are not allowed in string literals
+    string e = unicode"tab	";
+    string f = "line
+"; //~^ ERROR: unescaped newline
+}
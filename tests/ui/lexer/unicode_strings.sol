@@ -0,0 +1,11 @@
+contract C {
+    string constant a = unicode"héllo";
+    string constant b = unicode"🦀 é \x41";
+    // `e` followed by a combining acute accent.
+    string constant c = unicode"é";
+    string constant d = unicode'日本' unicode"語";
+
+    string constant e = "🦀"; //~ ERROR: unicode characters are not allowed in string literals
+    string constant f = "é"; //~ ERROR: unicode characters are not allowed in string literals
+    string constant g = "\u00e9 is fine";
+}
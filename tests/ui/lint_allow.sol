@@ -0,0 +1,7 @@
+//@ compile-flags: -A 3445
+
+contract C {
+    function receive() public {}
+    function fallback() public {}
+    function(uint256 named) external f; //~ WARN: named function type parameters are deprecated
+}
@@ -0,0 +1,6 @@
+//@ compile-flags: -D 3445 -A 6162
+
+contract C {
+    function receive() public {} //~ ERROR: function named `receive`
+    function(uint256 named) external f;
+}
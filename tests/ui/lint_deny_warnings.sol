@@ -0,0 +1,7 @@
+//@ compile-flags: -D warnings -W 6162
+
+contract C {
+    function receive() public {} //~ ERROR: function named `receive`
+    function fallback() public {} //~ ERROR: function named `fallback`
+    function(uint256 named) external f; //~ WARN: named function type parameters are deprecated
+}
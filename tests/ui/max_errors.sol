@@ -0,0 +1,10 @@
+//@ compile-flags: --max-errors=2
+
+contract C {
+    function f(uint256 x) public pure {
+        x + = 1; //~ ERROR: unexpected whitespace in `+=`
+        x := 1; //~ ERROR: `:=` is only valid in inline assembly
+        x = +x;
+        x << = 1;
+    }
+}
@@ -0,0 +1,6 @@
+//@ compile-flags: --no-warnings
+
+contract C {
+    function(uint256 named) external f;
+    function(uint256 other) external g;
+}
@@ -0,0 +1,6 @@
+//@ compile-flags: --no-warnings -D 6162
+
+contract C {
+    function receive() public {}
+    function(uint256 named) external f; //~ ERROR: named function type parameters are deprecated
+}
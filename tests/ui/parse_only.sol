@@ -0,0 +1,7 @@
+//@ compile-flags: --parse-only
+
+contract A {
+    function f() public {
+        uint x = ; //~ ERROR: expected one of
+    }
+}
@@ -0,0 +1,5 @@
+//@ compile-flags: --parse-only
+
+// Semantic errors are not reported when only parsing.
+contract A {}
+contract A {}
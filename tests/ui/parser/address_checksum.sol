@@ -0,0 +1,7 @@
+contract C {
+    address constant VALID = 0x52908400098527886E0F7030069857D2E4169EE7;
+    uint256 constant LOWERCASE = 0xffffffffffffffffffffffffffffffffffffffff;
+    address constant INVALID = 0x52908400098527886E0F7030069857D2E4169Ee7; //~ WARN: this looks like an address but has an invalid checksum
+    uint256 constant UPPERCASE = 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF;
+    uint256 constant NOT_AN_ADDRESS = 0x52908400098527886E0F7030069857D2E4169E;
+}
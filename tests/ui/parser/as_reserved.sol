@@ -0,0 +1,6 @@
+// `as` is a keyword, unlike `from`, which is only used contextually in imports.
+contract C {
+    function f() public {
+        uint as = 1; //~ ERROR: expected
+    }
+}
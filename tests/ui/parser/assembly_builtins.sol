@@ -0,0 +1,25 @@
+contract C {
+    function f(uint x) public view {
+        assembly {
+            let a := address()
+            let b := byte(0, x)
+            let c := balance(address())
+            codecopy(0, 0, codesize())
+            mstore(0, a)
+            return(0, 0)
+        }
+    }
+
+    function g() public pure {
+        assembly {
+            revert(0, 0)
+        }
+    }
+
+    function h() public pure {
+        assembly {
+            let return := 1 //~ ERROR: expected identifier, found keyword `return`
+            let codesize := 2 //~ ERROR: expected identifier, found Yul EVM builtin keyword `codesize`
+        }
+    }
+}
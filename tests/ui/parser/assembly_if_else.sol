@@ -0,0 +1,18 @@
+contract C {
+    function f(uint x) public pure returns (uint y) {
+        assembly {
+            if lt(x, 2) { y := 1 }
+            if x { y := 1 } else { y := 2 } //~ ERROR: Yul `if` statements have no `else` branch
+            if x { y := 1 } else if y { y := 2 } //~ ERROR: Yul `if` statements have no `else` branch
+            switch x
+            case 0 { y := 1 }
+            default { y := 2 }
+        }
+    }
+
+    function g(uint x) public pure {
+        assembly {
+            if x {} 5 //~ ERROR: expected one of
+        }
+    }
+}
@@ -0,0 +1,11 @@
+contract C {
+    function f() public pure {
+        assembly {
+            let a := 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff //~ ERROR: number literal too large
+            let b := 115792089237316195423570985008687907853269984665640564039457584007913129639936 //~ ERROR: number literal too large
+            // 2^256 - 1 is the largest valid literal.
+            let c := 0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
+            let d := 115792089237316195423570985008687907853269984665640564039457584007913129639935
+        }
+    }
+}
@@ -0,0 +1,13 @@
+contract C {
+    uint x;
+    uint[] arr;
+    function f(bytes calldata b) external {
+        assembly {
+            sstore(x.slot, 1)
+            let o := x.offset
+            let l := b.length
+            b.offset := 0
+            let s := arr.slot
+        }
+    }
+}
@@ -0,0 +1,14 @@
+contract C {
+    function f(uint256 a) public {
+        assembly {
+            let x := verbatim_1i_1o(hex"600202", a)
+            verbatim_0i_0o("")
+            verbatim_0i_0o(hex"00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff0011223344556677")
+            verbatim_1i_0o(hex"00", "abcdefghijklmnopqrstuvwxyz0123456") //~ ERROR: string literal too long
+            pop(verbatim_2i_1o(hex"01", a)) //~ ERROR: `verbatim_2i_1o` expects 3 arguments, but got 2
+            verbatim_0i_0o() //~ ERROR: `verbatim_0i_0o` expects 1 argument, but got 0
+            verbatim_1i_0o(a, a) //~ ERROR: the first argument of `verbatim` must be a string literal
+            verbatim_01i_0o(a)
+        }
+    }
+}
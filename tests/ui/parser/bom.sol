@@ -0,0 +1,8 @@
+﻿// SPDX-License-Identifier: MIT
+pragma solidity >=0.8.0;
+
+contract C {
+    function f() public {
+        break; //~ ERROR: `break` outside of a loop
+    }
+}
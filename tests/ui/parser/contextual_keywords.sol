@@ -0,0 +1,21 @@
+// Words that are only keywords in specific positions can be used as identifiers elsewhere.
+contract C {
+    uint global;
+    uint error;
+    uint revert;
+    uint from;
+    uint layout;
+    uint at;
+    uint transient;
+
+    function f(uint global_, uint from_) public returns (uint error_) {
+        uint revert_ = global_ + from_;
+        error_ = revert_;
+        uint at_ = layout + at + transient;
+        at_;
+    }
+}
+
+using {f} for uint global;
+
+function f(uint) pure {}
@@ -0,0 +1,8 @@
+//@ compile-flags: --stop-after parsing
+
+contract C {}
+abstract contract A is C {}
+interface I {}
+library L {}
+
+abstract interface J {} //~ ERROR: expected `contract`
@@ -0,0 +1,22 @@
+//@ compile-flags: --stop-after parsing
+
+struct S {
+    uint256[] memory a; //~ ERROR: data locations are not allowed here
+}
+
+event E(bytes memory b); //~ ERROR: data locations are not allowed here
+
+contract C {
+    uint256[] x;
+
+    function f(
+        uint256[] memory a,
+        string calldata b,
+        S storage c,
+        bytes memory memory d //~ ERROR: data location already specified
+    ) internal returns (bytes memory, S storage) {
+        uint256[] storage y = x;
+        S memory s;
+        bytes calldata z = b;
+    }
+}
@@ -0,0 +1,8 @@
+contract C {
+    uint256 public internal a; //~ ERROR: visibility already specified
+    uint256 constant immutable b = 1; //~ ERROR: mutability already specified
+    event E(uint256 indexed indexed x); //~ ERROR: `indexed` already specified
+
+    function f() external public {} //~ ERROR: visibility already specified
+    function g() virtual public virtual {} //~ ERROR: virtual already specified
+}
@@ -0,0 +1,7 @@
+// Contextual keywords like `_`, `revert` and `throw` must not be listed as expected tokens.
+
+contract C {
+    function f() public {
+        = 1; //~ ERROR: expected one of
+    }
+}
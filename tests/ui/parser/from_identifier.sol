@@ -0,0 +1,24 @@
+contract from {
+    event Transfer(address indexed from, address indexed to);
+
+    function from_(address from) public pure returns (address) {
+        return from;
+    }
+
+    function transferFrom(address from, address to) public {
+        emit Transfer(from, to);
+    }
+}
+
+contract C {
+    struct S {
+        uint256 from;
+    }
+
+    function from() public {
+        uint from;
+        from = 1;
+        S memory s = S({from: from});
+        s.from += 1;
+    }
+}
@@ -0,0 +1,12 @@
+// Like in solc, these keywords are reserved and cannot be used as identifiers.
+contract C {
+    function f() public {
+        uint indexed = 1; //~ ERROR: `indexed` is not allowed here
+        uint anonymous = 1; //~ ERROR: expected identifier, found keyword `anonymous`
+        uint override = 1; //~ ERROR: `override` is not allowed here
+        uint virtual = 1; //~ ERROR: `virtual` is not allowed here
+        uint emit = 1; //~ ERROR: expected identifier, found keyword `emit`
+        uint unchecked = 1; //~ ERROR: expected identifier, found keyword `unchecked`
+        uint abstract = 1; //~ ERROR: expected identifier, found keyword `abstract`
+    }
+}
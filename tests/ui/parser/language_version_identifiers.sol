@@ -0,0 +1,50 @@
+//@ compile-flags: --language-version 0.4.16
+// Every keyword introduced after 0.4.0 is an ordinary identifier here.
+
+contract C {
+    uint view;
+    uint pure;
+    uint emit;
+    uint constructor;
+    uint alias;
+    uint apply;
+    uint auto;
+    uint calldata;
+    uint copyof;
+    uint define;
+    uint immutable;
+    uint implements;
+    uint macro;
+    uint mutable;
+    uint override;
+    uint partial;
+    uint promise;
+    uint reference;
+    uint sealed;
+    uint sizeof;
+    uint supports;
+    uint typedef;
+    uint unchecked;
+    uint fallback;
+    uint receive;
+    uint virtual;
+    uint gwei;
+    uint unicode;
+
+    function f(uint calldata, uint immutable) public returns (uint) {
+        emit = 2;
+        unchecked = gwei + unicode;
+        virtual = override + calldata + immutable;
+        constructor;
+        return view + pure;
+    }
+}
+
+contract D {
+    function fallback() public {}
+    function receive() public {}
+
+    function g() public {
+        receive();
+    }
+}
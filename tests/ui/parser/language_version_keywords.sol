@@ -0,0 +1,11 @@
+//@ compile-flags: --language-version 0.4.21
+
+contract C {
+    uint constructor = 1;
+
+    function f() public {
+        uint unchecked = constructor;
+        unchecked;
+        uint emit; //~ ERROR: found keyword `emit`
+    }
+}
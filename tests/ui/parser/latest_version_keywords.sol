@@ -0,0 +1,3 @@
+contract C {
+    uint constructor = 1; //~ ERROR: expected identifier, found keyword `constructor`
+}
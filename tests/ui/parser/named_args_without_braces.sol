@@ -0,0 +1,18 @@
+struct Point {
+    uint256 x;
+    uint256 y;
+}
+
+event Moved(Point p);
+
+contract C {
+    function f() public {
+        Point memory p = Point(x: 1, y: 2); //~ ERROR: named arguments must be enclosed in braces
+        p = Point({x: 1, y: 2});
+        emit Moved(p: p); //~ ERROR: named arguments must be enclosed in braces
+        uint256 z = true ? 1 : 2;
+        g(z > 0 ? z : 1);
+    }
+
+    function g(uint256) public {}
+}
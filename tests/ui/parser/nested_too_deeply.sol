@@ -0,0 +1,10 @@
+//@ compile-flags: --max-parse-depth 8
+
+function f() {
+    uint a = ((((((1))))));
+    uint b = ((((((((((1)))))))))); //~ ERROR: expression nested too deeply
+}
+
+function g(bool x) {
+    if (x) { if (x) { if (x) { if (x) { if (x) { if (x) { if (x) { if (x) {} } } } } } } } //~ ERROR: statement nested too deeply
+}
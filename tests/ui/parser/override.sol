@@ -0,0 +1,33 @@
+interface IA {
+    function f() external;
+}
+
+interface IB {
+    function f() external;
+}
+
+abstract contract A {
+    uint256 public v;
+
+    function g() public virtual;
+    function h() public virtual;
+    function k() public virtual;
+}
+
+contract C is IA, IB, A {
+    uint256 public override v;
+
+    // Multiple bases.
+    function f() external override(IA, IB) {}
+    // Bare `override`, in both orders with `virtual`.
+    function g() public virtual override {}
+    function h() public override virtual {}
+    // Single base.
+    function k() public override(A) {}
+}
+
+contract D {
+    function a() public override override {} //~ ERROR: override already specified
+    function b() public virtual virtual {} //~ ERROR: virtual already specified
+    function c() public override() {} //~ ERROR: expected identifier, found `)`
+}
@@ -0,0 +1,8 @@
+//@ compile-flags: --warn-conflicting-pragmas
+
+pragma solidity ^0.8.0;
+pragma solidity >=0.8.4 <0.9.0;
+pragma solidity 0.8.x || ^0.7.0;
+pragma solidity ^0.7.0; //~ WARN: conflicting version pragmas
+
+contract C {}
@@ -0,0 +1,8 @@
+// Conflicting version pragmas are only reported with `--warn-conflicting-pragmas`.
+
+pragma solidity ^0.8.0;
+pragma solidity >=0.8.4 <0.9.0;
+pragma solidity 0.8.x || ^0.7.0;
+pragma solidity ^0.7.0;
+
+contract C {}
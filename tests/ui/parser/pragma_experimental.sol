@@ -0,0 +1,14 @@
+pragma solidity >=0.8.0;
+pragma abicoder v1;
+pragma abicoder v2;
+pragma experimental ABIEncoderV2;
+pragma experimental SMTChecker;
+pragma experimental "SMTChecker";
+pragma experimental solidity;
+//~^ ERROR: experimental solidity features are not supported
+pragma experimental Foo;
+//~^ WARN: unknown experimental feature `Foo`
+pragma abicoder v3;
+//~^ ERROR: unknown pragma
+
+contract C {}
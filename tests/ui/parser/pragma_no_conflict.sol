@@ -0,0 +1,10 @@
+//@ compile-flags: --warn-conflicting-pragmas
+
+pragma solidity ^0.8.0;
+pragma solidity >0.8.4;
+pragma solidity >=0.7.0 <0.8.6;
+pragma solidity 0.8 - 0.9;
+pragma solidity ~0.8;
+pragma solidity =0.8.5 || 0.7.6;
+
+contract C {}
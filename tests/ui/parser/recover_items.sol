@@ -0,0 +1,21 @@
+function a() {
+    uint x = ; //~ ERROR: expected one of
+}
+
+function b() {
+    uint y = 1 +; //~ ERROR: expected one of
+}
+
+contract C {
+    function c() public {
+        if (true) {
+            return (; //~ ERROR: expected one of
+        }
+    }
+}
+
+contract D {}
+
+struct S {
+    uint z
+} //~ ERROR: expected
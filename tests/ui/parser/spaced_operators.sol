@@ -0,0 +1,11 @@
+contract C {
+    function f(uint256 x) public pure returns (uint256) {
+        x >> = 1; //~ ERROR: unexpected whitespace in `>>=`
+        x > >= 1; //~ ERROR: unexpected whitespace in `>>=`
+        x + = 1; //~ ERROR: unexpected whitespace in `+=`
+        x = x < < 2; //~ ERROR: unexpected whitespace in `<<`
+        x := 1; //~ ERROR: `:=` is only valid in inline assembly
+        x >>= 1;
+        return x - -x;
+    }
+}
@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: MIT
+// SPDX-License-Identifier: MIT
+/* SPDX-License-Identifier: GPL-3.0 */ //~ ERROR: multiple SPDX license identifiers found
+
+contract C {}
@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: MIT
+pragma solidity >=0.8.0;
+pragma abicoder v2;
+
+contract C {}
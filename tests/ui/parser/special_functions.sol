@@ -0,0 +1,20 @@
+contract A {
+    receive() external payable {}
+    fallback() external payable {}
+}
+
+contract B {
+    fallback(bytes calldata input) external returns (bytes memory) {
+        return input;
+    }
+}
+
+contract C {
+    fallback(bytes calldata) external payable returns (bytes memory output) {}
+}
+
+// `receive` and `fallback` can still be used as regular function names.
+contract D {
+    function receive() public {} //~ WARN: function named `receive`
+    function fallback() public {} //~ WARN: function named `fallback`
+}
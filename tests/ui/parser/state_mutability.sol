@@ -0,0 +1,14 @@
+contract C {
+    function a() public view {}
+    function b() external payable {}
+    function c() pure internal virtual {}
+    function d() virtual view override public {}
+
+    function e() public pure view {} //~ ERROR: state mutability already specified as `pure`
+    function f() view public view {} //~ ERROR: `view` already specified
+    function g() external payable pure {} //~ ERROR: state mutability already specified as `payable`
+
+    function(uint256) external view returns (bool) h;
+    function(uint256) external payable i;
+    function(uint256) view pure external j; //~ ERROR: state mutability already specified as `view`
+}
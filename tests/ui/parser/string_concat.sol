@@ -0,0 +1,13 @@
+contract C {
+    string constant a = "a" "b" 'c';
+    string constant b = unicode"a" unicode"b";
+    bytes constant c = hex"00" hex'11' hex"22_33";
+    bytes constant d = hex"00_11_22";
+
+    bytes constant e = hex"123"; //~ ERROR: odd number of hex nibbles
+    bytes constant f = hex"00" hex"1"; //~ ERROR: odd number of hex nibbles
+
+    string constant g = "a" hex"00"; //~ ERROR: cannot concatenate string and hex string literals
+    string constant h = "a" unicode"b"; //~ ERROR: cannot concatenate string and unicode string literals
+    bytes constant i = hex"00" "a" hex"11"; //~ ERROR: cannot concatenate hex string and string literals
+}
@@ -0,0 +1,4 @@
+struct S {
+    uint x = 1; //~ ERROR: struct fields cannot have initializers
+    uint y;
+}
@@ -0,0 +1,5 @@
+function f(uint a, uint b) {}
+
+function g() {
+    f(1, 2,); //~ ERROR: trailing `,` separator is not allowed
+}
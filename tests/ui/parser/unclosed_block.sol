@@ -0,0 +1,17 @@
+contract A {
+    function f() public { //~ ERROR: unclosed block
+        uint x = 1;
+
+    function g() public {}
+}
+
+contract B { //~ ERROR: unclosed block
+    function h() public {}
+
+contract C {
+    function i() public {
+        if (true) {
+            return;
+        }
+    }
+}
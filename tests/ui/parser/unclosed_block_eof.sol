@@ -0,0 +1 @@
+contract C { function f() { //~ ERROR: unclosed block
\ No newline at end of file
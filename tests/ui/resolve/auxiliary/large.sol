@@ -0,0 +1,13 @@
+// This file is larger than the limit set in `max_file_size.sol`.
+
+contract Large {
+    uint256 public a;
+    uint256 public b;
+    uint256 public c;
+
+    function set(uint256 x, uint256 y, uint256 z) public {
+        a = x;
+        b = y;
+        c = z;
+    }
+}
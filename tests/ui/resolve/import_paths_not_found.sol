@@ -0,0 +1,3 @@
+//@ compile-flags: -I /solar-nonexistent-a --include-path /solar-nonexistent-b --base-path /solar-nonexistent-base
+
+import "missing.sol"; //~ ERROR: file missing.sol not found
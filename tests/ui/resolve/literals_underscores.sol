@@ -11,4 +11,9 @@ contract LT {
     uint256 g = 1_.4e10 + 3.4e_10; //~ERROR: invalid use of underscores in number literal
     //~^ERROR: invalid use of underscores in number literal
 
+    uint256 h = 1_0 + 0x1_e0 + 1_0.5_0e1_0; // OK
+    uint256 i = 1_.5; //~ERROR: invalid use of underscores in number literal
+    uint256 j = 0x_1; //~ERROR: invalid use of underscores in number literal
+    uint256 k = 1__0 ether; //~ERROR: invalid use of underscores in number literal
+    uint256 l = 1000___; //~ERROR: invalid use of underscores in number literal
 }
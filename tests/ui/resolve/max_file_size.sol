@@ -0,0 +1,4 @@
+//@ compile-flags: --max-file-size 256
+
+import "./auxiliary/udvt.sol";
+import "./auxiliary/large.sol"; //~ ERROR: is too large
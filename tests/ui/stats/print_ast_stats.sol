@@ -0,0 +1,9 @@
+//@ compile-flags: --print ast-stats --stop-after parsing
+
+contract C {
+    uint256 public number;
+
+    function set(uint256 n) public {
+        number = n + 1;
+    }
+}
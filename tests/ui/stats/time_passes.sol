@@ -0,0 +1,6 @@
+//@ compile-flags: --time-passes --stop-after parsing
+//@normalize-stderr-test: "(?m) +[0-9.]+(ns|µs|ms|s)$" -> " TIME"
+
+contract C {
+    function f() public {}
+}
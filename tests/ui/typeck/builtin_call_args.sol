@@ -0,0 +1,21 @@
+error MyError(uint256);
+
+contract C {
+    function f(bool x) public pure {
+        require(x);
+        require(x, "msg");
+        require(x, MyError(1));
+        assert(x);
+
+        require(); //~ ERROR: wrong number of arguments to `require`: expected 1 or 2, found 0
+        require(x, "a", "b"); //~ ERROR: wrong number of arguments to `require`: expected 1 or 2, found 3
+        assert(); //~ ERROR: wrong number of arguments to `assert`: expected 1, found 0
+        assert(x, "msg"); //~ ERROR: wrong number of arguments to `assert`: expected 1, found 2
+    }
+
+    function g() public pure {
+        revert();
+        revert("msg");
+        revert("a", "b"); //~ ERROR: wrong number of arguments to `revert`: expected 0 or 1, found 2
+    }
+}
@@ -0,0 +1,12 @@
+contract C {
+    uint constant A = 1 / 0; //~ ERROR: division by zero
+    uint constant B = 2 + 2;
+    uint constant C = 5 % (2 - 2); //~ ERROR: modulo by zero
+    uint constant D = 4 / (2 * 1);
+
+    function f(uint x) public pure returns (uint) {
+        x /= 0; //~ ERROR: division by zero
+        x = x / (1 - 1); //~ ERROR: division by zero
+        return x / 1;
+    }
+}